@@ -6,14 +6,119 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{
     parse_macro_input, spanned::Spanned, AttrStyle, Attribute, Data, DeriveInput, Expr, ExprArray,
-    GenericArgument, Ident, LitBool, LitStr, Meta, PathArguments, Type,
+    GenericArgument, Ident, LitBool, LitStr, Meta, PathArguments, Token, Type,
 };
 
+/// SVG elements, as named in `leptos::svg`. Tags are matched exactly. Tags that are also HTML elements (`a`, `script`,
+/// `style` and `title`) are not listed, so they are rendered as HTML elements.
+const SVG_TAGS: [&str; 61] = [
+    "animate",
+    "animateMotion",
+    "animateTransform",
+    "circle",
+    "clipPath",
+    "defs",
+    "desc",
+    "discard",
+    "ellipse",
+    "feBlend",
+    "feColorMatrix",
+    "feComponentTransfer",
+    "feComposite",
+    "feConvolveMatrix",
+    "feDiffuseLighting",
+    "feDisplacementMap",
+    "feDistantLight",
+    "feDropShadow",
+    "feFlood",
+    "feFuncA",
+    "feFuncB",
+    "feFuncG",
+    "feFuncR",
+    "feGaussianBlur",
+    "feImage",
+    "feMerge",
+    "feMergeNode",
+    "feMorphology",
+    "feOffset",
+    "fePointLight",
+    "feSpecularLighting",
+    "feSpotLight",
+    "feTile",
+    "feTurbulence",
+    "filter",
+    "foreignObject",
+    "g",
+    "hatch",
+    "hatchpath",
+    "image",
+    "line",
+    "linearGradient",
+    "marker",
+    "mask",
+    "metadata",
+    "mpath",
+    "path",
+    "pattern",
+    "polygon",
+    "polyline",
+    "radialGradient",
+    "rect",
+    "set",
+    "stop",
+    "svg",
+    "switch",
+    "symbol",
+    "text",
+    "textPath",
+    "tspan",
+    "view",
+];
+
+/// Returns the element function for a tag, and whether it is an SVG element.
+fn element(tag: &str) -> (TokenStream, bool) {
+    match SVG_TAGS.iter().find(|svg_tag| **svg_tag == tag) {
+        Some(svg_tag) => (
+            format!("::leptos::svg::{svg_tag}()")
+                .parse::<TokenStream>()
+                .expect("String should parse as TokenStream."),
+            true,
+        ),
+        None => (
+            format!("::leptos::html::{tag}()")
+                .parse::<TokenStream>()
+                .expect("String should parse as TokenStream."),
+            false,
+        ),
+    }
+}
+
+/// Returns whether a type is `Style` or `MaybeProp<Style>`.
+fn is_style_type(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+
+    path.path.segments.last().is_some_and(|segment| {
+        segment.ident == "Style"
+            || (segment.ident == "MaybeProp"
+                && match &segment.arguments {
+                    PathArguments::AngleBracketed(arguments) => arguments.args.first().is_some_and(
+                        |arg| matches!(arg, GenericArgument::Type(ty) if is_style_type(ty)),
+                    ),
+                    _ => false,
+                })
+    })
+}
+
 #[derive(Debug, Default)]
 struct StructComponentAttrArgs {
     tag: Option<String>,
     dynamic_tag: Option<Vec<(Expr, String)>>,
     no_children: Option<bool>,
+    presentation_attributes: Option<bool>,
+    merge: Option<Expr>,
+    strategy: Option<Expr>,
 }
 
 fn parse_struct_component_attr(attr: &Attribute) -> Result<StructComponentAttrArgs, syn::Error> {
@@ -51,6 +156,31 @@ fn parse_struct_component_attr(attr: &Attribute) -> Result<StructComponentAttrAr
 
                 args.no_children = Some(value.value());
 
+                Ok(())
+            } else if meta.path.is_ident("presentation_attributes") {
+                // The value is optional, `presentation_attributes` is the same as `presentation_attributes = true`.
+                let value = if meta.input.peek(Token![=]) {
+                    meta.value()
+                        .and_then(|value| value.parse::<LitBool>())?
+                        .value()
+                } else {
+                    true
+                };
+
+                args.presentation_attributes = Some(value);
+
+                Ok(())
+            } else if meta.path.is_ident("merge") {
+                let value = meta.value().and_then(|value| value.parse::<Expr>())?;
+
+                args.merge = Some(value);
+
+                Ok(())
+            } else if meta.path.is_ident("strategy") {
+                let value = meta.value().and_then(|value| value.parse::<Expr>())?;
+
+                args.strategy = Some(value);
+
                 Ok(())
             } else {
                 Err(meta.error("unknown property"))
@@ -93,6 +223,10 @@ pub fn derive_struct_component(input: proc_macro::TokenStream) -> proc_macro::To
         let ident = derive_input.ident.clone();
 
         let mut attributes: Vec<TokenStream> = vec![];
+        // Replaces the `style` attribute on SVG elements, to render presentation attributes. Only used with
+        // `#[struct_component(presentation_attributes)]`, because presentation attributes have a lower priority than
+        // author style sheets, unlike inline styles. Tags rendered as HTML elements (e.g. `a`) keep the inline style.
+        let mut svg_style: Option<(usize, TokenStream)> = None;
         // let mut attribute_checked: Option<TokenStream> = None;
         // let mut attribute_value: Option<TokenStream> = None;
         let mut listeners: Vec<TokenStream> = vec![];
@@ -102,6 +236,8 @@ pub fn derive_struct_component(input: proc_macro::TokenStream) -> proc_macro::To
 
         for field in &data_struct.fields {
             if let Some(ident) = &field.ident {
+                let mut merge: Option<TokenStream> = None;
+
                 if let Some(attr) = field
                     .attrs
                     .iter()
@@ -114,6 +250,19 @@ pub fn derive_struct_component(input: proc_macro::TokenStream) -> proc_macro::To
 
                                 continue;
                             }
+
+                            if let Some(other) = args.merge {
+                                let strategy = args
+                                    .strategy
+                                    .map(|strategy| quote! { #strategy })
+                                    .unwrap_or_else(|| {
+                                        quote! { ::core::default::Default::default() }
+                                    });
+
+                                merge = Some(quote! {
+                                    .merge(#other, #strategy)
+                                });
+                            }
                         }
                         Err(error) => {
                             return error.to_compile_error().into();
@@ -199,10 +348,43 @@ pub fn derive_struct_component(input: proc_macro::TokenStream) -> proc_macro::To
                     Type::Path(path) => {
                         let first = path.path.segments.first();
 
+                        if ident == "style"
+                            && args.presentation_attributes.unwrap_or(false)
+                            && is_style_type(&field.ty)
+                        {
+                            let style = if first.is_some_and(|segment| segment.ident == "MaybeProp")
+                            {
+                                quote! {
+                                    ::leptos_style::Style::reactive(move || self.style.get().unwrap_or_default() #merge)
+                                }
+                            } else {
+                                quote! {
+                                    self.style #merge
+                                }
+                            };
+
+                            svg_style = Some((
+                                attributes.len(),
+                                quote! {
+                                    .add_any_attr(::leptos_style::SvgStyle::from(#style))
+                                },
+                            ));
+                        }
+
                         attributes.push(
                             if first.is_some_and(|segment| segment.ident == "MaybeProp") {
+                                if let Some(merge) = merge {
+                                    quote! {
+                                        .#ident(move || self.#ident.get().unwrap_or_default() #merge)
+                                    }
+                                } else {
+                                    quote! {
+                                        .#ident(move || self.#ident.get())
+                                    }
+                                }
+                            } else if let Some(merge) = merge {
                                 quote! {
-                                    .#ident(move || self.#ident.get())
+                                    .#ident(self.#ident #merge)
                                 }
                             } else {
                                 quote! {
@@ -236,14 +418,25 @@ pub fn derive_struct_component(input: proc_macro::TokenStream) -> proc_macro::To
             }
         });
 
-        let tag_methods = quote! {
-            // TODO: dynamic attributes
+        let tag_methods = |svg: bool| {
+            let attributes =
+                attributes
+                    .iter()
+                    .enumerate()
+                    .map(|(index, attribute)| match &svg_style {
+                        Some((style_index, svg_style)) if svg && index == *style_index => svg_style,
+                        _ => attribute,
+                    });
 
-            #node_ref
-                #(#attributes)*
-                #(#listeners)*
-                #children
-                .into_any()
+            quote! {
+                // TODO: dynamic attributes
+
+                #node_ref
+                    #(#attributes)*
+                    #(#listeners)*
+                    #children
+                    .into_any()
+            }
         };
 
         if let Some((tag_ident, tags)) = dynamic_tag {
@@ -251,9 +444,8 @@ pub fn derive_struct_component(input: proc_macro::TokenStream) -> proc_macro::To
             let tags = tags
                 .iter()
                 .map(|(_, tag)| {
-                    let tag = format!("::leptos::html::{tag}()")
-                        .parse::<TokenStream>()
-                        .expect("String should parse as TokenStream.");
+                    let (tag, svg) = element(tag);
+                    let tag_methods = tag_methods(svg);
 
                     quote! {
                         #tag
@@ -273,9 +465,8 @@ pub fn derive_struct_component(input: proc_macro::TokenStream) -> proc_macro::To
             }
             .into()
         } else if let Some(tag) = args.tag {
-            let tag = format!("::leptos::html::{tag}()")
-                .parse::<TokenStream>()
-                .expect("String should parse as TokenStream.");
+            let (tag, svg) = element(&tag);
+            let tag_methods = tag_methods(svg);
 
             quote! {
                 impl #ident {
@@ -287,9 +478,9 @@ pub fn derive_struct_component(input: proc_macro::TokenStream) -> proc_macro::To
             }
             .into()
         } else {
-            return syn::Error::new(derive_input.span(), "`#[struct_component(tag = \"\")] or #[struct_component(dynamic_tag = true)]` is required")
-                    .to_compile_error()
-                    .into();
+            syn::Error::new(derive_input.span(), "`#[struct_component(tag = \"\")] or #[struct_component(dynamic_tag = true)]` is required")
+                .to_compile_error()
+                .into()
         }
     } else {
         syn::Error::new(derive_input.span(), "expected struct")
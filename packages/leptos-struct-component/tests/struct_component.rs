@@ -2,7 +2,8 @@ use std::fmt::{self, Display};
 
 use leptos::{ev::MouseEvent, prelude::*};
 use leptos_node_ref::AnyNodeRef;
-use leptos_struct_component::{struct_component, StructComponent};
+use leptos_struct_component::StructComponent;
+use leptos_style::{MergeStrategy, Style};
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum BoxAs {
@@ -130,3 +131,113 @@ pub fn App() -> impl IntoView {
         </Box>
     }
 }
+
+#[derive(Clone, StructComponent)]
+#[struct_component(tag = "div", no_children = true)]
+pub struct OverlayChildProps {
+    // Global attributes
+    #[struct_component(merge = [("pointer-events", "none")], strategy = MergeStrategy::Override)]
+    pub style: Style,
+}
+
+#[test]
+fn test_merge() {
+    let owner = Owner::new();
+    owner.set();
+
+    let props = OverlayChildProps {
+        style: Style::from([("pointer-events", "auto"), ("color", "red")]),
+    };
+
+    assert_eq!(
+        "<div style=\"pointer-events: none; color: red;\"></div>",
+        props.render().to_html()
+    );
+}
+
+#[derive(Clone, StructComponent)]
+#[struct_component(tag = "rect", no_children = true)]
+pub struct RectChildProps {
+    // Global attributes
+    pub style: Style,
+}
+
+#[test]
+fn test_svg_inline_style() {
+    let owner = Owner::new();
+    owner.set();
+
+    let props = RectChildProps {
+        style: Style::from("fill: red; stroke-width: 2;"),
+    };
+
+    assert_eq!(
+        "<rect style=\"fill: red; stroke-width: 2;\"></rect>",
+        props.render().to_html()
+    );
+}
+
+#[derive(Clone, StructComponent)]
+#[struct_component(tag = "circle", no_children = true, presentation_attributes)]
+pub struct CircleChildProps {
+    // Global attributes
+    #[struct_component(merge = [("fill", "currentColor")], strategy = MergeStrategy::Preserve)]
+    pub style: Style,
+}
+
+#[test]
+fn test_svg_presentation_attributes() {
+    let owner = Owner::new();
+    owner.set();
+
+    let props = CircleChildProps {
+        style: Style::from("stroke-width: 2; transform: rotate(45deg);"),
+    };
+
+    assert_eq!(
+        "<circle fill=\"currentColor\" stroke-width=\"2\" style=\"transform: rotate(45deg);\"></circle>",
+        props.render().to_html()
+    );
+}
+
+#[derive(Clone, StructComponent)]
+#[struct_component(tag = "path", no_children = true, presentation_attributes)]
+pub struct PathChildProps {
+    // Global attributes
+    pub style: Style,
+}
+
+#[test]
+fn test_svg_presentation_attributes_geometry() {
+    let owner = Owner::new();
+    owner.set();
+
+    let props = PathChildProps {
+        style: Style::from("stroke: red; d: path('M 0 0 L 10 10'); transform: scale(2);"),
+    };
+
+    assert_eq!(
+        "<path stroke=\"red\" style=\"d: path('M 0 0 L 10 10'); transform: scale(2);\"></path>",
+        props.render().to_html()
+    );
+}
+
+#[derive(Clone, StructComponent)]
+#[struct_component(tag = "a", no_children = true, presentation_attributes)]
+pub struct LinkChildProps {
+    // Global attributes
+    pub style: Style,
+}
+
+#[test]
+fn test_svg_presentation_attributes_html_tag() {
+    let owner = Owner::new();
+    owner.set();
+
+    // `a` is rendered as an HTML element, so the style stays inline.
+    let props = LinkChildProps {
+        style: Style::from("fill: red;"),
+    };
+
+    assert_eq!("<a style=\"fill: red;\"></a>", props.render().to_html());
+}
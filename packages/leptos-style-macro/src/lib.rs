@@ -0,0 +1,313 @@
+//! Macros for [Leptos Style](https://github.com/RustForWeb/leptos-utils/tree/main/packages/leptos-style).
+
+extern crate proc_macro;
+
+use leptos_style_properties::{edit_distance, strip_important, PROPERTIES};
+use proc_macro2::{Delimiter, LineColumn, Literal, Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned};
+use syn::{Error, Expr, LitStr};
+
+enum Segment {
+    Text(String),
+    Expr(Box<Expr>),
+}
+
+struct Declaration {
+    property: String,
+    property_span: Span,
+    value: Vec<Segment>,
+    important: bool,
+}
+
+fn validate_property(property: &str, span: Span) -> Result<(), Error> {
+    // Custom properties and vendor-prefixed properties are not checked.
+    if property.starts_with('-') || PROPERTIES.binary_search(&property).is_ok() {
+        return Ok(());
+    }
+
+    let suggestion = PROPERTIES
+        .iter()
+        .map(|known| (edit_distance(property, known), known))
+        .filter(|(distance, _)| *distance <= 2)
+        .min()
+        .map(|(_, known)| format!(", did you mean `{known}`?"))
+        .unwrap_or_default();
+
+    Err(Error::new(
+        span,
+        format!("unknown CSS property `{property}`{suggestion}"),
+    ))
+}
+
+fn push_text(segments: &mut Vec<Segment>, text: &str) {
+    if let Some(Segment::Text(previous)) = segments.last_mut() {
+        previous.push_str(text);
+    } else {
+        segments.push(Segment::Text(text.to_string()));
+    }
+}
+
+/// Appends tokens to `segments`, inserting a space wherever the source has whitespace between tokens.
+fn push_tokens(
+    segments: &mut Vec<Segment>,
+    tokens: impl IntoIterator<Item = TokenTree>,
+    previous_end: &mut Option<LineColumn>,
+) -> Result<(), Error> {
+    for token in tokens {
+        if previous_end.is_some_and(|end| end != token.span().start()) {
+            push_text(segments, " ");
+        }
+
+        match &token {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                segments.push(Segment::Expr(Box::new(syn::parse2(group.stream())?)));
+            }
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    _ => ("", ""),
+                };
+
+                push_text(segments, open);
+                *previous_end = Some(group.span_open().end());
+                push_tokens(segments, group.stream(), previous_end)?;
+                if previous_end.is_some_and(|end| end != group.span_close().start()) {
+                    push_text(segments, " ");
+                }
+                push_text(segments, close);
+            }
+            token => push_text(segments, &token.to_string()),
+        }
+
+        *previous_end = Some(token.span().end());
+    }
+
+    Ok(())
+}
+
+fn string_literal(literal: &Literal) -> Option<String> {
+    syn::parse2::<LitStr>(TokenTree::Literal(literal.clone()).into())
+        .ok()
+        .map(|literal| literal.value())
+}
+
+fn parse_declaration(tokens: Vec<TokenTree>) -> Result<Declaration, Error> {
+    let colon = tokens
+        .iter()
+        .position(|token| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ':'))
+        .ok_or_else(|| Error::new(tokens[0].span(), "expected `:` after property"))?;
+
+    let (property_tokens, value_tokens) = tokens.split_at(colon);
+    let mut value_tokens = value_tokens[1..].to_vec();
+
+    let mut property = String::new();
+    for token in property_tokens {
+        match token {
+            TokenTree::Ident(ident) => property.push_str(&ident.to_string()),
+            TokenTree::Punct(punct) if punct.as_char() == '-' => property.push('-'),
+            // Numeric segments (e.g. `--level-1`) are lexed as integer literals.
+            TokenTree::Literal(literal)
+                if property.ends_with('-')
+                    && literal
+                        .to_string()
+                        .chars()
+                        .all(|char| char.is_ascii_alphanumeric()) =>
+            {
+                property.push_str(&literal.to_string())
+            }
+            token => return Err(Error::new(token.span(), "expected property name")),
+        }
+    }
+    let property_span = property_tokens
+        .iter()
+        .rev()
+        .find(|token| matches!(token, TokenTree::Ident(_) | TokenTree::Literal(_)))
+        .map(TokenTree::span)
+        .ok_or_else(|| Error::new(tokens[0].span(), "expected property name"))?;
+
+    validate_property(&property, property_span)?;
+
+    let mut important = matches!(
+        value_tokens.as_slice(),
+        [.., TokenTree::Punct(punct), TokenTree::Ident(ident)]
+            if punct.as_char() == '!' && ident == "important"
+    );
+    if important {
+        value_tokens.truncate(value_tokens.len() - 2);
+    }
+
+    if value_tokens.is_empty() {
+        return Err(Error::new(
+            tokens[colon].span(),
+            format!("expected value for `{property}`"),
+        ));
+    }
+
+    let string = match value_tokens.as_slice() {
+        [TokenTree::Literal(literal)] => {
+            string_literal(literal).map(|string| match strip_important(&string) {
+                Some(stripped) => {
+                    important = true;
+                    stripped.to_string()
+                }
+                None => string,
+            })
+        }
+        _ => None,
+    };
+    if value_tokens.len() > 1 && value_tokens[0].span().start() == value_tokens[0].span().end() {
+        return Err(Error::new(
+            value_tokens[0].span(),
+            "`style!` requires Rust 1.88 or later to reconstruct whitespace in values",
+        ));
+    }
+
+    let mut value = vec![];
+    match string {
+        // A string literal is used as written, for values that are not valid Rust tokens (e.g. `1em`).
+        Some(string) => value.push(Segment::Text(string)),
+        None => push_tokens(&mut value, value_tokens, &mut None)?,
+    }
+
+    Ok(Declaration {
+        property,
+        property_span,
+        value,
+        important,
+    })
+}
+
+fn expand(input: TokenStream) -> Result<TokenStream, Error> {
+    let mut declarations = vec![];
+    let mut tokens = vec![];
+
+    for token in input {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == ';' => {
+                if !tokens.is_empty() {
+                    declarations.push(parse_declaration(std::mem::take(&mut tokens))?);
+                }
+            }
+            token => tokens.push(token),
+        }
+    }
+    if !tokens.is_empty() {
+        declarations.push(parse_declaration(tokens)?);
+    }
+
+    if declarations.is_empty() {
+        return Ok(quote! {
+            ::leptos_style::Style::default()
+        });
+    }
+
+    let entries = declarations.into_iter().map(|declaration| {
+        let Declaration {
+            property,
+            property_span,
+            value,
+            important,
+        } = declaration;
+
+        let value = match value.as_slice() {
+            [Segment::Text(text)] => quote! {
+                ::std::borrow::Cow::Borrowed(#text)
+            },
+            [Segment::Expr(expr)] => quote! {
+                ::std::borrow::Cow::Owned(::std::string::ToString::to_string(&(#expr)))
+            },
+            segments => {
+                let mut format = String::new();
+                let mut arguments = vec![];
+                for segment in segments {
+                    match segment {
+                        Segment::Text(text) => {
+                            format.push_str(&text.replace('{', "{{").replace('}', "}}"))
+                        }
+                        Segment::Expr(expr) => {
+                            format.push_str("{}");
+                            arguments.push(expr);
+                        }
+                    }
+                }
+
+                quote! {
+                    ::std::borrow::Cow::Owned(::std::format!(#format, #((#arguments)),*))
+                }
+            }
+        };
+
+        quote_spanned! {property_span=>
+            (
+                ::std::borrow::Cow::Borrowed(#property),
+                ::leptos_style::StyleValue {
+                    value: #value,
+                    important: #important,
+                },
+            )
+        }
+    });
+
+    Ok(quote! {
+        ::leptos_style::Style::from([#(#entries),*])
+    })
+}
+
+/// Creates a structured [`Style`](https://docs.rs/leptos-style/latest/leptos_style/struct.Style.html)
+/// from CSS declarations.
+///
+/// Property names are checked at compile time. Custom properties (`--name`) and vendor-prefixed
+/// properties (`-webkit-name`) are allowed without checks. Rust expressions implementing
+/// [`Display`](std::fmt::Display) can be interpolated in values using braces.
+///
+/// Values are written as Rust tokens, so values that are not valid Rust tokens have to be written as a string
+/// literal (e.g. `"1em"`) or interpolated (e.g. `{"#1e1e1e"}`). This includes hex colors (e.g. `#1e1e1e`) and
+/// lengths in `em` or `ex` units (e.g. `1em` or `1.5ex`), which Rust reads as numbers with an incomplete exponent.
+/// A value written as a single string literal is used without its quotes, so a CSS string as the whole value needs
+/// nested quotes (e.g. `content: "'→'"`).
+///
+/// ```ignore
+/// use leptos_style::style;
+///
+/// let width = 100;
+///
+/// let style = style! {
+///     color: red;
+///     font-size: "1.5em";
+///     padding: 1rem 0.5rem !important;
+///     width: {width}px;
+///     --accent: {"#1e1e1e"};
+/// };
+/// ```
+#[proc_macro]
+pub fn style(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(input.into())
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Creates a generated class name from CSS declarations, registering a scoped rule with
+/// [`scoped`](https://docs.rs/leptos-style/latest/leptos_style/fn.scoped.html).
+///
+/// Accepts the same syntax as [`style!`].
+///
+/// ```ignore
+/// use leptos_style::css;
+///
+/// let class = css! {
+///     color: red;
+///     padding: 1rem;
+/// };
+/// ```
+#[proc_macro]
+pub fn css(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(input.into())
+        .map(|style| {
+            quote! {
+                ::leptos_style::scoped(#style)
+            }
+        })
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
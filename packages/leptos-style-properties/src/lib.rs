@@ -0,0 +1,57 @@
+//! CSS property names and helpers shared by
+//! [Leptos Style](https://github.com/RustForWeb/leptos-utils/tree/main/packages/leptos-style) and its macros.
+mod properties;
+
+pub use crate::properties::*;
+
+/// Levenshtein distance between two strings, in characters.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != *b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Splits a trailing `!important` off a value.
+pub fn strip_important(value: &str) -> Option<&str> {
+    let value = value.trim_end();
+    let (value, priority) = value.rsplit_once('!')?;
+
+    priority
+        .trim_start()
+        .eq_ignore_ascii_case("important")
+        .then(|| value.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(0, edit_distance("color", "color"));
+        assert_eq!(1, edit_distance("colr", "color"));
+        assert_eq!(1, edit_distance("flx", "flex"));
+        assert_eq!(2, edit_distance("rde", "red"));
+        assert_eq!(5, edit_distance("", "color"));
+        assert_eq!(1, edit_distance("colör", "color"));
+    }
+
+    #[test]
+    fn test_strip_important() {
+        assert_eq!(Some("red"), strip_important("red !important"));
+        assert_eq!(Some("red"), strip_important("red ! IMPORTANT "));
+        assert_eq!(None, strip_important("red"));
+        assert_eq!(None, strip_important("red !default"));
+    }
+}
@@ -0,0 +1,76 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use leptos::{prelude::Owner, tachys::html::style::IntoStyle};
+use leptos_style::{Style, StyleValue};
+
+const DECLARATIONS: [(&str, &str); 8] = [
+    ("display", "flex"),
+    ("align-items", "center"),
+    ("justify-content", "space-between"),
+    ("padding", "0.5rem 1rem"),
+    ("border-bottom", "1px solid var(--border)"),
+    ("color", "var(--foreground)"),
+    ("background-color", "var(--background)"),
+    ("font-size", "0.875rem"),
+];
+
+fn structured() -> Style {
+    Style::from(DECLARATIONS)
+}
+
+fn construct(c: &mut Criterion) {
+    c.bench_function("construct", |b| b.iter(|| black_box(structured())));
+
+    c.bench_function("construct static", |b| {
+        b.iter(|| black_box(Style::from_static(DECLARATIONS)))
+    });
+
+    c.bench_function("construct with property", |b| {
+        b.iter(|| {
+            black_box(
+                Style::new()
+                    .with_property("display", "flex")
+                    .with_property("gap", StyleValue::important("1rem"))
+                    .with_property("backgroundColor", "white"),
+            )
+        })
+    });
+}
+
+fn render(c: &mut Criterion) {
+    let owner = Owner::new();
+    owner.set();
+
+    let style = structured();
+    c.bench_function("display", |b| b.iter(|| black_box(&style).to_string()));
+
+    // A row in a list, as rendered during SSR.
+    c.bench_function("to html", |b| {
+        b.iter(|| {
+            let mut html = String::with_capacity(256);
+            structured().to_html(&mut html);
+            black_box(html)
+        })
+    });
+
+    c.bench_function("to html static", |b| {
+        b.iter(|| {
+            let mut html = String::with_capacity(256);
+            Style::from_static(DECLARATIONS).to_html(&mut html);
+            black_box(html)
+        })
+    });
+
+    let string = Style::from(style.to_string());
+    c.bench_function("to html string", |b| {
+        b.iter(|| {
+            let mut html = String::with_capacity(256);
+            black_box(string.clone()).to_html(&mut html);
+            black_box(html)
+        })
+    });
+}
+
+criterion_group!(benches, construct, render);
+criterion_main!(benches);
@@ -0,0 +1,612 @@
+use std::{
+    fmt::{self, Display},
+    time::Duration,
+};
+
+use leptos::logging::error;
+
+use crate::{
+    parse::{split_components, split_list},
+    sheet::{class_name, register},
+    style::Style,
+    value::StyleValue,
+};
+
+/// Formats a duration as a CSS time, in seconds if it is a whole number of seconds and in milliseconds otherwise.
+fn format_time(duration: Duration) -> String {
+    if duration.subsec_nanos() == 0 {
+        format!("{}s", duration.as_secs())
+    } else if duration.subsec_nanos().is_multiple_of(1_000_000) {
+        format!("{}ms", duration.as_millis())
+    } else {
+        format!("{}s", duration.as_secs_f64())
+    }
+}
+
+/// Returns whether a component of a `transition` item is a time.
+fn is_time(component: &str) -> bool {
+    component
+        .strip_suffix("ms")
+        .or_else(|| component.strip_suffix('s'))
+        .is_some_and(|number| number.parse::<f64>().is_ok())
+}
+
+/// Returns the property of a `transition` item, `all` if it has none.
+pub(crate) fn transition_property(item: &str) -> &str {
+    split_components(item)
+        .into_iter()
+        .find(|component| {
+            !is_time(component)
+                && !TimingFunction::is_timing_function(component)
+                && !matches!(*component, "normal" | "allow-discrete")
+        })
+        .unwrap_or("all")
+}
+
+/// An easing function for transitions and animations.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TimingFunction {
+    Ease,
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    StepStart,
+    StepEnd,
+    CubicBezier(f64, f64, f64, f64),
+    Steps(u32),
+}
+
+impl TimingFunction {
+    fn is_timing_function(component: &str) -> bool {
+        matches!(
+            component,
+            "ease" | "linear" | "ease-in" | "ease-out" | "ease-in-out" | "step-start" | "step-end"
+        ) || ["cubic-bezier(", "steps(", "linear("]
+            .iter()
+            .any(|function| component.starts_with(function))
+    }
+}
+
+impl Display for TimingFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ease => write!(f, "ease"),
+            Self::Linear => write!(f, "linear"),
+            Self::EaseIn => write!(f, "ease-in"),
+            Self::EaseOut => write!(f, "ease-out"),
+            Self::EaseInOut => write!(f, "ease-in-out"),
+            Self::StepStart => write!(f, "step-start"),
+            Self::StepEnd => write!(f, "step-end"),
+            Self::CubicBezier(x1, y1, x2, y2) => write!(f, "cubic-bezier({x1}, {y1}, {x2}, {y2})"),
+            Self::Steps(steps) => write!(f, "steps({steps})"),
+        }
+    }
+}
+
+/// A single item of a `transition` value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transition {
+    pub property: String,
+    pub duration: Duration,
+    pub timing_function: Option<TimingFunction>,
+    pub delay: Option<Duration>,
+}
+
+impl Transition {
+    pub fn new(property: &str, duration: Duration) -> Self {
+        Self {
+            property: property.to_string(),
+            duration,
+            timing_function: None,
+            delay: None,
+        }
+    }
+
+    pub fn with_timing_function(mut self, timing_function: TimingFunction) -> Self {
+        self.timing_function = Some(timing_function);
+        self
+    }
+
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+}
+
+impl Display for Transition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.property, format_time(self.duration))?;
+        if let Some(timing_function) = self.timing_function {
+            write!(f, " {timing_function}")?;
+        }
+        if let Some(delay) = self.delay {
+            write!(f, " {}", format_time(delay))?;
+        }
+        Ok(())
+    }
+}
+
+/// A `transition` value, with one transition per property.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Transitions(pub Vec<Transition>);
+
+impl Transitions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a transition, replacing an existing transition of the same property.
+    pub fn with(mut self, transition: Transition) -> Self {
+        match self
+            .0
+            .iter_mut()
+            .find(|existing| existing.property == transition.property)
+        {
+            Some(existing) => *existing = transition,
+            None => self.0.push(transition),
+        }
+        self
+    }
+}
+
+impl Display for Transitions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "none");
+        }
+
+        for (index, transition) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{transition}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Transition> for Transitions {
+    fn from(value: Transition) -> Self {
+        Self(vec![value])
+    }
+}
+
+impl<const N: usize> From<[Transition; N]> for Transitions {
+    fn from(value: [Transition; N]) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl FromIterator<Transition> for Transitions {
+    fn from_iter<T: IntoIterator<Item = Transition>>(iter: T) -> Self {
+        iter.into_iter().fold(Self::new(), Self::with)
+    }
+}
+
+impl From<Transition> for StyleValue {
+    fn from(value: Transition) -> Self {
+        StyleValue::new(value.to_string())
+    }
+}
+
+impl From<Transitions> for StyleValue {
+    fn from(value: Transitions) -> Self {
+        StyleValue::new(value.to_string())
+    }
+}
+
+/// The number of times an animation is played.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IterationCount {
+    Count(f64),
+    Infinite,
+}
+
+impl Display for IterationCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Count(count) => write!(f, "{count}"),
+            Self::Infinite => write!(f, "infinite"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AnimationDirection {
+    Normal,
+    Reverse,
+    Alternate,
+    AlternateReverse,
+}
+
+impl Display for AnimationDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Normal => write!(f, "normal"),
+            Self::Reverse => write!(f, "reverse"),
+            Self::Alternate => write!(f, "alternate"),
+            Self::AlternateReverse => write!(f, "alternate-reverse"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FillMode {
+    None,
+    Forwards,
+    Backwards,
+    Both,
+}
+
+impl Display for FillMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Forwards => write!(f, "forwards"),
+            Self::Backwards => write!(f, "backwards"),
+            Self::Both => write!(f, "both"),
+        }
+    }
+}
+
+/// A single item of an `animation` value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Animation {
+    /// Name of the keyframes, e.g. returned by [`Keyframes::register`].
+    pub name: String,
+    pub duration: Duration,
+    pub timing_function: Option<TimingFunction>,
+    pub delay: Option<Duration>,
+    pub iteration_count: Option<IterationCount>,
+    pub direction: Option<AnimationDirection>,
+    pub fill_mode: Option<FillMode>,
+}
+
+impl Animation {
+    pub fn new(name: &str, duration: Duration) -> Self {
+        Self {
+            name: name.to_string(),
+            duration,
+            timing_function: None,
+            delay: None,
+            iteration_count: None,
+            direction: None,
+            fill_mode: None,
+        }
+    }
+
+    pub fn with_timing_function(mut self, timing_function: TimingFunction) -> Self {
+        self.timing_function = Some(timing_function);
+        self
+    }
+
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    pub fn with_iteration_count(mut self, iteration_count: IterationCount) -> Self {
+        self.iteration_count = Some(iteration_count);
+        self
+    }
+
+    pub fn with_direction(mut self, direction: AnimationDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn with_fill_mode(mut self, fill_mode: FillMode) -> Self {
+        self.fill_mode = Some(fill_mode);
+        self
+    }
+}
+
+impl Display for Animation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The name is written last, so it cannot be mistaken for a keyword.
+        write!(f, "{}", format_time(self.duration))?;
+        if let Some(timing_function) = self.timing_function {
+            write!(f, " {timing_function}")?;
+        }
+        if let Some(delay) = self.delay {
+            write!(f, " {}", format_time(delay))?;
+        }
+        if let Some(iteration_count) = self.iteration_count {
+            write!(f, " {iteration_count}")?;
+        }
+        if let Some(direction) = self.direction {
+            write!(f, " {direction}")?;
+        }
+        if let Some(fill_mode) = self.fill_mode {
+            write!(f, " {fill_mode}")?;
+        }
+        write!(f, " {}", self.name)
+    }
+}
+
+/// An `animation` value, playing several animations at once.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Animations(pub Vec<Animation>);
+
+impl Animations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, animation: Animation) -> Self {
+        self.0.push(animation);
+        self
+    }
+}
+
+impl Display for Animations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "none");
+        }
+
+        for (index, animation) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{animation}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Animation> for Animations {
+    fn from(value: Animation) -> Self {
+        Self(vec![value])
+    }
+}
+
+impl<const N: usize> From<[Animation; N]> for Animations {
+    fn from(value: [Animation; N]) -> Self {
+        Self(value.to_vec())
+    }
+}
+
+impl FromIterator<Animation> for Animations {
+    fn from_iter<T: IntoIterator<Item = Animation>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl From<Animation> for StyleValue {
+    fn from(value: Animation) -> Self {
+        StyleValue::new(value.to_string())
+    }
+}
+
+impl From<Animations> for StyleValue {
+    fn from(value: Animations) -> Self {
+        StyleValue::new(value.to_string())
+    }
+}
+
+/// A `@keyframes` definition, registered in the style sheet under a generated name.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use leptos_style::{Animation, Keyframes, Style};
+///
+/// # let owner = leptos::prelude::Owner::new();
+/// # owner.set();
+/// let fade_in = Keyframes::new()
+///     .frame("from", [("opacity", "0")])
+///     .frame("to", [("opacity", "1")])
+///     .register();
+///
+/// let style = Style::new().with_animation(Animation::new(&fade_in, Duration::from_millis(200)));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Keyframes {
+    /// Keyframe selectors (e.g. `from`, `50%` or `0%, 100%`) and their declarations.
+    pub frames: Vec<(String, Style)>,
+}
+
+impl Keyframes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a keyframe, e.g. `from`, `50%` or `0%, 100%`.
+    pub fn frame<I: Into<Style>>(mut self, selector: &str, style: I) -> Self {
+        self.frames.push((selector.to_string(), style.into()));
+        self
+    }
+
+    /// Adds a keyframe at a percentage of the animation.
+    pub fn at<I: Into<Style>>(self, percentage: f64, style: I) -> Self {
+        self.frame(&format!("{percentage}%"), style)
+    }
+
+    /// Returns the `@keyframes` rule for `name`. Declarations are sanitized with the
+    /// [`SanitizePolicy`](crate::SanitizePolicy) from context, unsafe declarations are always removed.
+    pub fn to_css(&self, name: &str) -> String {
+        let mut css = format!("@keyframes {name} {{");
+        for (selector, style) in &self.frames {
+            if selector.contains(['{', '}', ';']) {
+                error!("invalid keyframe selector `{selector}`");
+                continue;
+            }
+
+            css.push_str(&format!(
+                " {selector} {{ {} }}",
+                style.clone().prepare_for_rule()
+            ));
+        }
+        css.push_str(" }");
+        css
+    }
+
+    /// Returns the generated name for these keyframes.
+    pub fn name(&self) -> String {
+        class_name(&self.to_css("&"))
+    }
+
+    /// Returns the generated name for these keyframes, registering the `@keyframes` rule.
+    ///
+    /// The rule is added to the [`StyleSheet`](crate::StyleSheet) from context if there is one (e.g. during SSR).
+    /// Otherwise, it is injected into the document head on the client.
+    pub fn register(&self) -> String {
+        let name = self.name();
+        register(&name, self.to_css(&name));
+        name
+    }
+}
+
+impl Style {
+    /// Adds a transition, replacing an existing transition of the same property.
+    ///
+    /// Use [`MergeStrategy::Combine`](crate::MergeStrategy::Combine) to combine the transitions of two styles.
+    pub fn with_transition(self, transition: Transition) -> Self {
+        self.map_static(move |mut style| {
+            let mut items = style
+                .get("transition")
+                .map(|value| {
+                    split_list(&value)
+                        .into_iter()
+                        .filter(|item| *item != "none")
+                        .map(str::to_string)
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            let item = transition.to_string();
+
+            match items
+                .iter_mut()
+                .find(|existing| transition_property(existing) == transition.property)
+            {
+                Some(existing) => *existing = item,
+                None => items.push(item),
+            }
+
+            style.set("transition", items.join(", "));
+            style
+        })
+    }
+
+    /// Adds an animation, playing it together with existing animations.
+    pub fn with_animation(self, animation: Animation) -> Self {
+        self.map_static(move |mut style| {
+            let value = match style.get("animation") {
+                Some(value) if **value != *"none" => format!("{}, {animation}", value.value),
+                _ => animation.to_string(),
+            };
+
+            style.set("animation", value);
+            style
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos::prelude::Owner;
+
+    use super::*;
+    use crate::{merge::MergeStrategy, sheet::provide_style_sheet};
+
+    #[test]
+    fn test_transition() {
+        assert_eq!(
+            "opacity 200ms, transform 1s ease-out 50ms",
+            Transitions::from([
+                Transition::new("opacity", Duration::from_millis(200)),
+                Transition::new("transform", Duration::from_secs(1))
+                    .with_timing_function(TimingFunction::EaseOut)
+                    .with_delay(Duration::from_millis(50)),
+            ])
+            .to_string()
+        );
+        assert_eq!(
+            "opacity 1500ms, color 100ms steps(4)",
+            Transitions::new()
+                .with(Transition::new("opacity", Duration::from_millis(200)))
+                .with(
+                    Transition::new("color", Duration::from_millis(100))
+                        .with_timing_function(TimingFunction::Steps(4))
+                )
+                .with(Transition::new("opacity", Duration::from_millis(1500)))
+                .to_string()
+        );
+        assert_eq!("none", Transitions::new().to_string());
+
+        for (item, expected) in [
+            ("opacity 0.2s", "opacity"),
+            ("0.2s ease-in opacity", "opacity"),
+            ("1s cubic-bezier(0.1, 0.7, 1, 0.1) 0.5s", "all"),
+            ("display 1s allow-discrete", "display"),
+        ] {
+            assert_eq!(expected, transition_property(item), "{item}");
+        }
+    }
+
+    #[test]
+    fn test_with_transition() {
+        assert_eq!(
+            "transition: color 1s, opacity 300ms ease;",
+            Style::from("transition: color 1s, opacity 0.2s")
+                .with_transition(
+                    Transition::new("opacity", Duration::from_millis(300))
+                        .with_timing_function(TimingFunction::Ease)
+                )
+                .to_string()
+        );
+
+        assert_eq!(
+            Style::from([("transition", "opacity 0.2s, color 1s")]),
+            Style::from([("transition", "opacity 0.2s")]).merge(
+                Style::new().with_transition(Transition::new("color", Duration::from_secs(1))),
+                MergeStrategy::Combine
+            )
+        );
+    }
+
+    #[test]
+    fn test_animation() {
+        assert_eq!(
+            "animation: 2s ease-in-out infinite alternate pulse, 150ms 1s both fade;",
+            Style::new()
+                .with_animation(
+                    Animation::new("pulse", Duration::from_secs(2))
+                        .with_timing_function(TimingFunction::EaseInOut)
+                        .with_iteration_count(IterationCount::Infinite)
+                        .with_direction(AnimationDirection::Alternate)
+                )
+                .with_animation(
+                    Animation::new("fade", Duration::from_millis(150))
+                        .with_delay(Duration::from_secs(1))
+                        .with_fill_mode(FillMode::Both)
+                )
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_keyframes() {
+        let owner = Owner::new();
+        owner.set();
+
+        let sheet = provide_style_sheet();
+        let keyframes = Keyframes::new()
+            .frame("from", [("opacity", "0")])
+            .at(50.0, [("opacity", "0.5"), ("transform", "scale(1.1)")])
+            .frame("to", [("opacity", "1")]);
+
+        let name = keyframes.register();
+        assert!(name.starts_with("ls-"));
+        assert_eq!(name, keyframes.register());
+        assert_eq!(
+            format!(
+                "@keyframes {name} {{ from {{ opacity: 0; }} 50% {{ opacity: 0.5; transform: scale(1.1); }} \
+                 to {{ opacity: 1; }} }}"
+            ),
+            sheet.to_css()
+        );
+    }
+}
@@ -0,0 +1,342 @@
+use std::fmt::{self, Display};
+
+use indexmap::IndexMap;
+#[cfg(feature = "tailwind")]
+use leptos::prelude::use_context;
+use leptos::tachys::{html::class::IntoClass, renderer::Rndr};
+
+#[cfg(feature = "tailwind")]
+use crate::tailwind::TailwindConfig;
+
+/// Class names, with a flag for whether each class is active.
+///
+/// Class names are unique, so duplicates are removed. An inactive class removes the class from defaults, see
+/// [`Class::with_defaults`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Class(pub IndexMap<String, bool>);
+
+impl Class {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds default classes, keeping classes (and their active flags) in this class.
+    ///
+    /// With the `tailwind` feature, default classes conflicting with classes in this class are removed, using the
+    /// [`TailwindConfig`] from context.
+    pub fn with_defaults<I: Into<Self>>(self, defaults: I) -> Self {
+        let mut defaults: Class = defaults.into();
+        defaults.0.extend(self.0);
+
+        #[cfg(feature = "tailwind")]
+        let defaults =
+            defaults.resolve_conflicts(&use_context::<TailwindConfig>().unwrap_or_default());
+
+        defaults
+    }
+
+    /// Removes active classes overridden by a later class of the same Tailwind utility group.
+    #[cfg(feature = "tailwind")]
+    pub fn resolve_conflicts(mut self, config: &TailwindConfig) -> Self {
+        let merged = config
+            .merge(self.classes())
+            .into_iter()
+            .map(str::to_string)
+            .collect::<std::collections::HashSet<_>>();
+
+        self.0
+            .retain(|name, active| !*active || merged.contains(name));
+        self
+    }
+
+    /// Adds a class, active if `active` is `true`.
+    pub fn with_class(mut self, name: &str, active: bool) -> Self {
+        for name in name.split_whitespace() {
+            self.0.insert(name.to_string(), active);
+        }
+        self
+    }
+
+    /// Toggles whitespace-separated classes, adding them if they are missing.
+    pub fn toggle(&mut self, name: &str) {
+        for name in name.split_whitespace() {
+            let active = self.0.entry(name.to_string()).or_insert(false);
+            *active = !*active;
+        }
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.get(name).copied().unwrap_or(false)
+    }
+
+    /// Returns the active class names, in order.
+    pub fn classes(&self) -> impl Iterator<Item = &str> {
+        self.0
+            .iter()
+            .filter_map(|(name, active)| active.then_some(name.as_str()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.classes().next().is_none()
+    }
+}
+
+impl Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.classes().collect::<Vec<_>>().join(" "))
+    }
+}
+
+impl From<Option<&str>> for Class {
+    fn from(value: Option<&str>) -> Class {
+        value.map(Class::from).unwrap_or_default()
+    }
+}
+
+impl From<Option<String>> for Class {
+    fn from(value: Option<String>) -> Class {
+        value.map(Class::from).unwrap_or_default()
+    }
+}
+
+impl From<&str> for Class {
+    fn from(value: &str) -> Class {
+        Class::new().with_class(value, true)
+    }
+}
+
+impl From<String> for Class {
+    fn from(value: String) -> Class {
+        Class::from(value.as_str())
+    }
+}
+
+impl From<IndexMap<String, bool>> for Class {
+    fn from(value: IndexMap<String, bool>) -> Class {
+        value
+            .into_iter()
+            .fold(Class::new(), |class, (name, active)| {
+                class.with_class(&name, active)
+            })
+    }
+}
+
+impl From<Vec<&str>> for Class {
+    fn from(value: Vec<&str>) -> Class {
+        value
+            .into_iter()
+            .fold(Class::new(), |class, name| class.with_class(name, true))
+    }
+}
+
+impl From<Vec<String>> for Class {
+    fn from(value: Vec<String>) -> Class {
+        value
+            .into_iter()
+            .fold(Class::new(), |class, name| class.with_class(&name, true))
+    }
+}
+
+impl<const N: usize> From<[&str; N]> for Class {
+    fn from(value: [&str; N]) -> Class {
+        value
+            .into_iter()
+            .fold(Class::new(), |class, name| class.with_class(name, true))
+    }
+}
+
+impl<const N: usize> From<[(&str, bool); N]> for Class {
+    fn from(value: [(&str, bool); N]) -> Class {
+        value
+            .into_iter()
+            .fold(Class::new(), |class, (name, active)| {
+                class.with_class(name, active)
+            })
+    }
+}
+
+impl<const N: usize> From<[(String, bool); N]> for Class {
+    fn from(value: [(String, bool); N]) -> Class {
+        value
+            .into_iter()
+            .fold(Class::new(), |class, (name, active)| {
+                class.with_class(&name, active)
+            })
+    }
+}
+
+impl IntoClass for Class {
+    type AsyncOutput = Self;
+    type State = (leptos::tachys::renderer::types::Element, Self);
+    type Cloneable = Self;
+    type CloneableOwned = Self;
+
+    fn html_len(&self) -> usize {
+        self.classes().map(|name| name.len() + 1).sum()
+    }
+
+    fn to_html(self, class: &mut String) {
+        class.push_str(&self.to_string());
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(
+        self,
+        el: &leptos::tachys::renderer::types::Element,
+    ) -> Self::State {
+        if !FROM_SERVER {
+            let class_list = Rndr::class_list(el);
+            for name in self.classes() {
+                Rndr::add_class(&class_list, name);
+            }
+        }
+        (el.clone(), self)
+    }
+
+    fn build(self, el: &leptos::tachys::renderer::types::Element) -> Self::State {
+        // Classes are added individually, so classes from other attributes (e.g. `class:name`) are kept.
+        let class_list = Rndr::class_list(el);
+        for name in self.classes() {
+            Rndr::add_class(&class_list, name);
+        }
+        (el.clone(), self)
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        let (el, prev) = state;
+        if self != *prev {
+            let class_list = Rndr::class_list(el);
+
+            for name in prev.classes() {
+                if !self.contains(name) {
+                    Rndr::remove_class(&class_list, name);
+                }
+            }
+
+            for name in self.classes() {
+                if !prev.contains(name) {
+                    Rndr::add_class(&class_list, name);
+                }
+            }
+        }
+        *prev = self;
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        self
+    }
+
+    fn into_cloneable_owned(self) -> Self::CloneableOwned {
+        self
+    }
+
+    fn dry_resolve(&mut self) {}
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self
+    }
+
+    fn reset(state: &mut Self::State) {
+        let (el, prev) = state;
+        let class_list = Rndr::class_list(el);
+        for name in prev.classes() {
+            Rndr::remove_class(&class_list, name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!("", Class::default().to_string());
+        assert_eq!("", Class::from(None::<&str>).to_string());
+        assert_eq!("a b", Class::from("  a b a ").to_string());
+        assert_eq!("a b c", Class::from(["a b", "c", "a"]).to_string());
+        assert_eq!(
+            "a c",
+            Class::from([("a", true), ("b", false), ("c", true)]).to_string()
+        );
+        assert_eq!(
+            "b",
+            Class::from([("a", true), ("b", true), ("a", false)]).to_string()
+        );
+    }
+
+    #[test]
+    fn test_with_defaults() {
+        assert_eq!(
+            "button primary active",
+            Class::from([("active", true)])
+                .with_defaults("button primary")
+                .to_string()
+        );
+        assert_eq!(
+            "button primary",
+            Class::from("primary button")
+                .with_defaults("button")
+                .to_string()
+        );
+        assert_eq!(
+            "button",
+            Class::from([("primary", false)])
+                .with_defaults("button primary")
+                .to_string()
+        );
+        assert_eq!(
+            "button",
+            Class::default().with_defaults("button").to_string()
+        );
+    }
+
+    #[test]
+    fn test_toggle() {
+        let mut class = Class::from("a");
+        class.toggle("a");
+        class.toggle("b");
+
+        assert!(!class.contains("a"));
+        assert!(class.contains("b"));
+        assert_eq!("b", class.to_string());
+
+        class.toggle(" b  c ");
+        assert!(!class.contains("b"));
+        assert!(class.contains("c"));
+        assert!(!class.contains("b  c"));
+        assert_eq!("c", class.to_string());
+
+        assert!(!Class::from([("a", false)]).contains("a"));
+        assert!(Class::from([("a", false)]).is_empty());
+    }
+
+    #[cfg(feature = "tailwind")]
+    #[test]
+    fn test_with_defaults_tailwind() {
+        use leptos::prelude::{provide_context, Owner};
+
+        assert_eq!(
+            "py-2 rounded text-white bg-red-500 px-2",
+            Class::from("bg-red-500 px-2")
+                .with_defaults("px-4 py-2 rounded bg-blue-500 text-white")
+                .to_string()
+        );
+        assert_eq!(
+            "py-2 bg-blue-500 bg-primary",
+            Class::from("bg-primary")
+                .with_defaults("py-2 bg-blue-500")
+                .to_string()
+        );
+
+        let owner = Owner::new();
+        owner.set();
+        provide_context(TailwindConfig::default().with_colors(["primary"]));
+
+        assert_eq!(
+            "py-2 bg-primary",
+            Class::from("bg-primary")
+                .with_defaults("py-2 bg-blue-500")
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use leptos::{
+    prelude::{Get, Memo, Signal},
+    tachys::renderer::types::Element,
+    web_sys::Window,
+};
+use leptos_node_ref::AnyNodeRef;
+
+use crate::style::Style;
+
+/// A computed length in pixels, e.g. `12px`.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Pixels(pub f64);
+
+impl Display for Pixels {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}px", self.0)
+    }
+}
+
+/// Error returned when parsing a value that is not a pixel length.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParsePixelsError;
+
+impl Display for ParsePixelsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value is not a pixel length")
+    }
+}
+
+impl std::error::Error for ParsePixelsError {}
+
+impl FromStr for Pixels {
+    type Err = ParsePixelsError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value
+            .trim()
+            .strip_suffix("px")
+            .and_then(|value| value.parse().ok())
+            .map(Pixels)
+            .ok_or(ParsePixelsError)
+    }
+}
+
+/// Returns the window, or `None` if there is none, e.g. on the server or in a wasm SSR runtime without a DOM.
+#[cfg(target_arch = "wasm32")]
+fn window() -> Option<Window> {
+    leptos::web_sys::window()
+}
+
+/// Returns the window, or `None` if there is none, e.g. on the server or in a wasm SSR runtime without a DOM.
+#[cfg(not(target_arch = "wasm32"))]
+fn window() -> Option<Window> {
+    None
+}
+
+/// Reads the computed style of an element, with all properties as a structured style.
+///
+/// Returns `None` on the server.
+pub fn computed_style(el: &Element) -> Option<Style> {
+    let declaration = window()?.get_computed_style(el).ok().flatten()?;
+
+    Some(
+        (0..declaration.length())
+            .filter_map(|index| {
+                let property = declaration.item(index);
+                let value = declaration.get_property_value(&property).ok()?;
+                Some((property, value))
+            })
+            .collect(),
+    )
+}
+
+/// Reads a computed property of an element, parsed into `T`.
+///
+/// Returns `None` on the server, or if the value cannot be parsed.
+pub fn computed_value<T: FromStr>(el: &Element, property: &str) -> Option<T> {
+    window()?
+        .get_computed_style(el)
+        .ok()
+        .flatten()?
+        .get_property_value(property)
+        .ok()?
+        .parse()
+        .ok()
+}
+
+/// Returns the computed style of the element in `node_ref`, see [`computed_style`].
+///
+/// The style is read once each time the node reference is loaded, since reading it forces a layout. It is `None`
+/// until then, and always on the server. Computed styles are not observable, so changes to them (e.g. from a class
+/// change) are not tracked.
+pub fn use_computed_style(node_ref: AnyNodeRef) -> Signal<Option<Style>> {
+    Memo::new_with_compare(move |_| computed_style(&node_ref.get()?), |_, _| true).into()
+}
+
+/// Returns a computed property of the element in `node_ref`, see [`computed_value`].
+///
+/// ```no_run
+/// use leptos_node_ref::AnyNodeRef;
+/// use leptos_style::{use_computed_value, Pixels};
+///
+/// let node_ref = AnyNodeRef::new();
+/// let direction = use_computed_value::<String>(node_ref, "direction");
+/// let width = use_computed_value::<Pixels>(node_ref, "width");
+/// ```
+///
+/// The value is read once each time the node reference is loaded. It is `None` until then, and always on the server.
+pub fn use_computed_value<T: FromStr + Clone + Send + Sync + 'static>(
+    node_ref: AnyNodeRef,
+    property: &'static str,
+) -> Signal<Option<T>> {
+    Memo::new_with_compare(
+        move |_| computed_value(&node_ref.get()?, property),
+        |_, _| true,
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos::prelude::{GetUntracked, Owner};
+
+    use super::*;
+
+    #[test]
+    fn test_pixels() {
+        assert_eq!(Ok(Pixels(12.0)), "12px".parse());
+        assert_eq!(Ok(Pixels(-0.5)), " -0.5px ".parse());
+        assert_eq!(Err(ParsePixelsError), "1rem".parse::<Pixels>());
+        assert_eq!("12.5px", Pixels(12.5).to_string());
+    }
+
+    #[test]
+    fn test_server() {
+        let owner = Owner::new();
+        owner.set();
+
+        // Elements cannot be created outside of a browser, so only the missing window is checked here.
+        assert!(window().is_none());
+
+        let node_ref = AnyNodeRef::new();
+        assert_eq!(None, use_computed_style(node_ref).get_untracked());
+        assert_eq!(
+            None,
+            use_computed_value::<String>(node_ref, "direction").get_untracked()
+        );
+    }
+}
@@ -0,0 +1,350 @@
+use std::borrow::Cow;
+
+use leptos::prelude::{Get, Signal};
+
+use crate::{
+    merge::{merge_style_maps, overrides, MergeStrategy},
+    style::{hoist_custom_properties, InnerStyle, Style, StyleMap},
+    value::StyleValue,
+};
+
+fn into_map(style: Style) -> StyleMap {
+    style.0.map(InnerStyle::into_structured).unwrap_or_default()
+}
+
+/// Adds the declarations of `entries` to `style`, overriding existing values.
+fn add_entries(style: Style, entries: Style) -> Style {
+    if style.is_reactive() || entries.is_reactive() {
+        return Style::reactive(move || add_entries(style.current(), entries.current()));
+    }
+
+    Style(Some(InnerStyle::Structured(hoist_custom_properties(
+        merge_style_maps(into_map(style), into_map(entries), MergeStrategy::Override),
+    ))))
+}
+
+/// A style with declarations added while conditions are true, see [`Style::when_signal`].
+///
+/// When the style is rendered, only the declarations of entries whose condition changed are updated.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConditionalStyle {
+    pub base: Box<Style>,
+    pub entries: Vec<(Signal<bool>, StyleMap)>,
+}
+
+impl ConditionalStyle {
+    /// Returns the current declarations, tracking the conditions and a reactive base style.
+    pub fn current(&self) -> Style {
+        let active = self.active();
+        self.with_active(self.base.current(), &active)
+    }
+
+    /// Returns whether the condition of each entry is true, tracking the conditions.
+    pub(crate) fn active(&self) -> Vec<bool> {
+        self.entries
+            .iter()
+            .map(|(condition, _)| condition.get())
+            .collect()
+    }
+
+    /// Adds the entries marked in `active` to the current value of the base style.
+    pub(crate) fn with_active(&self, base: Style, active: &[bool]) -> Style {
+        self.entries
+            .iter()
+            .zip(active)
+            .filter(|(_, active)| **active)
+            .fold(base, |style, ((_, entries), _)| {
+                add_entries(style, Style(Some(InnerStyle::Structured(entries.clone()))))
+            })
+    }
+}
+
+/// The rendered value of a [`ConditionalStyle`].
+pub(crate) struct ConditionalRender {
+    /// The base style the declarations were rendered from, `None` if they were rendered from another style.
+    base: Option<Style>,
+    /// The prepared declarations of the base style and the entries, see [`Style::prepare_for_render`].
+    base_map: StyleMap,
+    entries: Vec<StyleMap>,
+    active: Vec<bool>,
+    /// The rendered declarations.
+    pub(crate) style: Style,
+}
+
+impl ConditionalRender {
+    pub(crate) fn new(style: Style) -> Self {
+        Self {
+            base: None,
+            base_map: StyleMap::default(),
+            entries: vec![],
+            active: vec![],
+            style,
+        }
+    }
+
+    /// Records the base style and the entries the rendered declarations were rendered from.
+    pub(crate) fn rendered_from(
+        &mut self,
+        base: Style,
+        style: &ConditionalStyle,
+        active: Vec<bool>,
+    ) {
+        let prepare = |style: Style| into_map(style.prepare_for_render());
+
+        self.base_map = prepare(base.clone());
+        if self.entries.len() != style.entries.len() {
+            self.entries = style
+                .entries
+                .iter()
+                .map(|(_, entries)| prepare(Style(Some(InnerStyle::Structured(entries.clone())))))
+                .collect();
+        }
+        self.base = Some(base);
+        self.active = active;
+    }
+
+    /// Updates the rendered declarations of entries whose condition changed, returning the updated properties.
+    ///
+    /// Returns `None` if the base style changed, so the whole style has to be rendered again.
+    pub(crate) fn toggle(
+        &mut self,
+        base: &Style,
+        active: Vec<bool>,
+    ) -> Option<Vec<Cow<'static, str>>> {
+        if !self
+            .base
+            .as_ref()
+            .is_some_and(|prev| prev.same_declarations(base))
+        {
+            return None;
+        }
+
+        let mut keys: Vec<Cow<'static, str>> = vec![];
+        for ((entries, prev), active) in self.entries.iter().zip(&self.active).zip(&active) {
+            if prev != active {
+                for key in entries.keys() {
+                    if !keys.contains(key) {
+                        keys.push(key.clone());
+                    }
+                }
+            }
+        }
+        self.active = active;
+
+        let values = keys
+            .iter()
+            .map(|key| self.value(key).cloned())
+            .collect::<Vec<_>>();
+        let map = self.style.structured_mut();
+        for (key, value) in keys.iter().zip(values) {
+            match value {
+                Some(value) => {
+                    map.insert(key.clone(), Some(value));
+                }
+                None => {
+                    map.shift_remove(key);
+                }
+            }
+        }
+
+        Some(keys)
+    }
+
+    /// Returns the value of `key` with the active entries applied to the base style, like [`add_entries`].
+    fn value(&self, key: &str) -> Option<&StyleValue> {
+        let mut value = self.base_map.get(key).map(Option::as_ref);
+        for (entries, _) in self
+            .entries
+            .iter()
+            .zip(&self.active)
+            .filter(|(_, active)| **active)
+        {
+            if let Some(entry) = entries.get(key) {
+                if overrides(value.flatten(), entry.as_ref()) {
+                    value = Some(entry.as_ref());
+                }
+            }
+        }
+
+        value.flatten().filter(|value| !value.is_empty())
+    }
+}
+
+impl Style {
+    /// Adds the declarations of `style` if `condition` is true, overriding existing values.
+    ///
+    /// ```
+    /// use leptos_style::Style;
+    ///
+    /// let is_open = true;
+    /// let style = Style::from([("color", "red")]).when(is_open, [("display", "block")]);
+    ///
+    /// assert_eq!("color: red; display: block;", style.to_string());
+    /// ```
+    pub fn when<I: Into<Style>>(self, condition: bool, style: I) -> Self {
+        match (condition, self.0) {
+            (false, inner) => Style(inner),
+            // Entries added later override conditional entries, so they are kept in order.
+            (true, Some(InnerStyle::Conditional(conditional))) => {
+                Style(Some(InnerStyle::Conditional(conditional)))
+                    .when_signal(Signal::stored(true), style)
+            }
+            (true, inner) => add_entries(Style(inner), style.into()),
+        }
+    }
+
+    /// Adds the declarations of `style` while `condition` is true, see [`Style::when`].
+    ///
+    /// The result is a [`ConditionalStyle`]. When it is rendered, only the declarations of `style` are set or
+    /// removed when `condition` changes.
+    pub fn when_signal<C: Into<Signal<bool>>, I: Into<Style>>(
+        self,
+        condition: C,
+        style: I,
+    ) -> Self {
+        let (condition, style) = (condition.into(), style.into());
+
+        // The declarations of a reactive style are not known in advance, so the whole style is updated.
+        if style.is_reactive() {
+            let base = self;
+            return Style::reactive(move || {
+                let base = base.current();
+                if condition.get() {
+                    add_entries(base, style.current())
+                } else {
+                    base
+                }
+            });
+        }
+
+        let entries = (condition, into_map(style));
+        Style(Some(InnerStyle::Conditional(match self.0 {
+            Some(InnerStyle::Conditional(mut conditional)) => {
+                conditional.entries.push(entries);
+                conditional
+            }
+            base => ConditionalStyle {
+                base: Box::new(Style(base)),
+                entries: vec![entries],
+            },
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos::prelude::{Owner, RwSignal, Set};
+
+    use super::*;
+
+    #[test]
+    fn test_when() {
+        let style = Style::from("color: red; display: none;");
+
+        assert_eq!(style, style.clone().when(false, [("display", "block")]));
+        assert_eq!(
+            Style::from("color: red; display: block;"),
+            style.clone().when(true, [("display", "block")])
+        );
+        assert_eq!(
+            Style::from("color: red; display: none !important;"),
+            Style::from("color: red; display: none !important;").when(true, [("display", "block")])
+        );
+        assert_eq!(
+            Style::from("padding: 1rem; display: block;"),
+            Style::default()
+                .when(true, [("display", "block")])
+                .when(false, [("display", "none")])
+                .with_defaults([("padding", "1rem")])
+        );
+    }
+
+    #[test]
+    fn test_when_signal() {
+        let owner = Owner::new();
+        owner.set();
+
+        let is_open = RwSignal::new(false);
+        let style = Style::from("color: red;")
+            .when_signal(is_open, [("display", "block")])
+            .with_defaults([("display", "none"), ("padding", "1rem")]);
+
+        assert!(style.is_reactive());
+        assert_eq!(
+            Style::from("display: none; padding: 1rem; color: red;"),
+            style.current()
+        );
+
+        is_open.set(true);
+        let current = style.current();
+        assert_eq!(
+            Style::from("display: block; padding: 1rem; color: red;"),
+            current
+        );
+        assert!(matches!(current.0, Some(InnerStyle::Structured(_))));
+
+        let is_hidden = RwSignal::new(true);
+        let style = style
+            .when_signal(is_hidden, [("display", "none")])
+            .when(true, [("color", "blue")]);
+        assert!(
+            matches!(&style.0, Some(InnerStyle::Conditional(conditional)) if conditional.entries.len() == 3)
+        );
+        assert_eq!(
+            Style::from("display: none; padding: 1rem; color: blue;"),
+            style.current()
+        );
+    }
+
+    #[test]
+    fn test_toggle() {
+        let owner = Owner::new();
+        owner.set();
+
+        let (is_open, is_large) = (RwSignal::new(false), RwSignal::new(false));
+        let style = Style::from("color: red; display: none;")
+            .when_signal(is_open, [("display", "block"), ("padding", "1rem")])
+            .when_signal(is_large, [("padding", "2rem")]);
+        let Some(InnerStyle::Conditional(conditional)) = style.0 else {
+            panic!("style should be conditional");
+        };
+
+        let base = conditional.base.current();
+        let mut render = ConditionalRender::new(conditional.current());
+        render.rendered_from(base.clone(), &conditional, vec![false, false]);
+
+        for (active, keys, expected) in [
+            (
+                vec![true, false],
+                vec!["display", "padding"],
+                "color: red; display: block; padding: 1rem;",
+            ),
+            (
+                vec![true, true],
+                vec!["padding"],
+                "color: red; display: block; padding: 2rem;",
+            ),
+            (
+                vec![false, true],
+                vec!["display", "padding"],
+                "color: red; display: none; padding: 2rem;",
+            ),
+            (
+                vec![false, false],
+                vec!["padding"],
+                "color: red; display: none;",
+            ),
+        ] {
+            assert_eq!(
+                Some(keys.into_iter().map(Cow::Borrowed).collect()),
+                render.toggle(&base, active)
+            );
+            assert_eq!(expected, render.style.to_string());
+        }
+
+        assert_eq!(
+            None,
+            render.toggle(&Style::from("color: blue;"), vec![false, false])
+        );
+    }
+}
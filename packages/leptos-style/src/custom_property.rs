@@ -0,0 +1,40 @@
+/// Returns whether `name` is a CSS custom property (e.g. `--primary-color`).
+pub fn is_custom_property(name: &str) -> bool {
+    name.starts_with("--")
+}
+
+/// Returns `name` as a custom property name, adding the `--` prefix if it is missing.
+pub fn custom_property_name(name: &str) -> String {
+    if is_custom_property(name) {
+        name.to_string()
+    } else {
+        format!("--{name}")
+    }
+}
+
+/// Creates a `var()` reference to a custom property.
+///
+/// ```
+/// use leptos_style::var;
+///
+/// assert_eq!("var(--primary)", var("primary"));
+/// ```
+pub fn var(name: &str) -> String {
+    format!("var({})", custom_property_name(name))
+}
+
+/// Creates a `var()` reference to a custom property with a fallback value.
+///
+/// The fallback can itself be a `var()` reference.
+///
+/// ```
+/// use leptos_style::{var, var_with_fallback};
+///
+/// assert_eq!(
+///     "var(--primary, var(--blue, blue))",
+///     var_with_fallback("primary", &var_with_fallback("blue", "blue")),
+/// );
+/// ```
+pub fn var_with_fallback(name: &str, fallback: &str) -> String {
+    format!("var({}, {fallback})", custom_property_name(name))
+}
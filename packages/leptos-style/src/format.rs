@@ -0,0 +1,241 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Write},
+};
+
+use crate::{
+    custom_property::is_custom_property,
+    parse::normalize_whitespace,
+    prefix::Prefixer,
+    style::{InnerStyle, Style, StyleMap},
+    value::StyleValue,
+};
+
+/// How styles are formatted when rendered.
+///
+/// The format used during rendering can be set by providing it as context:
+///
+/// ```
+/// use leptos::prelude::provide_context;
+/// use leptos_style::StyleFormat;
+///
+/// # let owner = leptos::prelude::Owner::new();
+/// # owner.set();
+/// provide_context(StyleFormat::Minified);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum StyleFormat {
+    /// Render declarations as written, string styles are passed through.
+    #[default]
+    Preserve,
+    /// Render canonical declarations, see [`Style::canonicalize`].
+    Canonical,
+    /// Render canonical declarations without optional whitespace and without the last semicolon.
+    Minified,
+}
+
+/// Canonicalizes the declarations of a structured map.
+///
+/// Property names are trimmed and lowercased (except custom properties, which are case-sensitive), values are
+/// whitespace-normalized, and empty values become unset. If a property is declared more than once, the last
+/// declaration is kept, in its position.
+pub(crate) fn canonical_map(map: StyleMap) -> StyleMap {
+    let mut canonical = StyleMap::with_capacity(map.len());
+
+    for (key, value) in map {
+        let key = key.trim();
+        let key: Cow<'static, str> = if is_custom_property(key) {
+            Cow::Owned(key.to_string())
+        } else {
+            Cow::Owned(key.to_ascii_lowercase())
+        };
+        let value = value
+            .map(|value| StyleValue {
+                value: normalize_whitespace(&value, false).into(),
+                important: value.important,
+            })
+            .filter(|value| !value.is_empty());
+
+        canonical.shift_remove(&key);
+        canonical.insert(key, value);
+    }
+
+    canonical
+}
+
+/// Writes canonical declarations, with vendor-prefixed declarations for the targets of `prefixer`.
+pub(crate) fn write_canonical<W: Write>(
+    map: &StyleMap,
+    prefixer: Option<&Prefixer>,
+    minified: bool,
+    f: &mut W,
+) -> fmt::Result {
+    let mut first = true;
+
+    for (key, value) in map {
+        let Some(value) = value else {
+            continue;
+        };
+        let declarations = match prefixer {
+            Some(prefixer) => prefixer.prefix_declaration(key, value),
+            None => vec![(key.to_string(), value.clone())],
+        };
+
+        for (key, value) in declarations {
+            match (minified, first) {
+                (true, true) => {}
+                (true, false) => f.write_char(';')?,
+                (false, true) => {}
+                (false, false) => f.write_char(' ')?,
+            }
+            first = false;
+
+            if minified {
+                write!(f, "{key}:{}", normalize_whitespace(&value, true))?;
+                if value.important {
+                    f.write_str("!important")?;
+                }
+            } else {
+                write!(f, "{key}: {value};")?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl Style {
+    /// Returns a structured style with canonical declarations: trimmed, deduplicated and whitespace-normalized, with
+    /// lowercase property names.
+    ///
+    /// Styles with the same canonical declarations in the same order are equal, regardless of formatting.
+    pub fn canonicalize(self) -> Self {
+        self.map_static(|style| {
+            Style(
+                style
+                    .0
+                    .map(|inner| InnerStyle::Structured(canonical_map(inner.into_structured()))),
+            )
+        })
+    }
+
+    /// Renders the canonical declarations, e.g. `color: red; padding: 1rem;`.
+    pub fn to_canonical_string(&self) -> String {
+        self.to_formatted_string(StyleFormat::Canonical)
+    }
+
+    /// Renders the canonical declarations without optional whitespace, e.g. `color:red;padding:1rem`.
+    pub fn to_minified_string(&self) -> String {
+        self.to_formatted_string(StyleFormat::Minified)
+    }
+
+    /// Renders the declarations in `format`.
+    pub fn to_formatted_string(&self, format: StyleFormat) -> String {
+        let mut string = String::new();
+        _ = self.write_formatted(format, None, &mut string);
+        string
+    }
+
+    /// Writes the declarations in `format`, adding vendor-prefixed declarations for the targets of `prefixer`.
+    pub(crate) fn write_formatted<W: Write>(
+        &self,
+        format: StyleFormat,
+        prefixer: Option<&Prefixer>,
+        f: &mut W,
+    ) -> fmt::Result {
+        match (format, prefixer) {
+            (StyleFormat::Preserve, Some(prefixer)) => self.write_prefixed(prefixer, f),
+            (StyleFormat::Preserve, None) => write!(f, "{self}"),
+            (format, prefixer) => write_canonical(
+                &canonical_map(self.current_map()),
+                prefixer,
+                format == StyleFormat::Minified,
+                f,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos::prelude::{provide_context, Owner};
+
+    use super::*;
+
+    #[test]
+    fn test_canonicalize() {
+        assert_eq!(
+            Style::from([
+                ("--Accent", StyleValue::new("#00f")),
+                ("margin", StyleValue::new("0 auto")),
+                ("color", StyleValue::important("rgb(0, 0, 0)")),
+            ]),
+            Style::from(
+                "  COLOR : red;  --Accent:#00f; margin:0   auto; color: rgb( 0,0,0 ) !important;"
+            )
+            .canonicalize()
+        );
+    }
+
+    #[test]
+    fn test_formatted_string() {
+        let style = Style::from(
+            "color:red;  font-family: \"Open  Sans\" ,sans-serif; Color: blue !important;",
+        );
+
+        assert_eq!(
+            "color:red;  font-family: \"Open  Sans\" ,sans-serif; Color: blue !important;",
+            style.to_formatted_string(StyleFormat::Preserve)
+        );
+        assert_eq!(
+            "font-family: \"Open  Sans\", sans-serif; color: blue !important;",
+            style.to_canonical_string()
+        );
+        assert_eq!(
+            "font-family:\"Open  Sans\",sans-serif;color:blue!important",
+            style.to_minified_string()
+        );
+        assert_eq!("", Style::default().to_minified_string());
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(
+            Style::from("color: red; padding: 1rem;"),
+            Style::from("COLOR:  red;padding:1rem")
+        );
+        assert_ne!(
+            Style::from("margin: 0; margin-top: 4px;"),
+            Style::from("margin-top: 4px; margin: 0;")
+        );
+        assert_eq!(Style::from("color: red;"), Style::from([("color", "red")]));
+        assert_eq!(Style::default(), Style::from(" ; "));
+        // Empty values unset a property when used as an override, e.g. in `Style::with_defaults`.
+        assert_ne!(Style::default(), Style::from("padding: ;"));
+        assert_ne!(
+            Style::from("color: red;"),
+            Style::from("color: red !important;")
+        );
+        assert_ne!(
+            Style::from("font-family: \"A  B\";"),
+            Style::from("font-family: \"A B\";")
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let owner = Owner::new();
+        owner.set();
+
+        let style = || Style::from("color : red;  transition: opacity  1s ;");
+
+        let mut html = String::new();
+        leptos::tachys::html::style::IntoStyle::to_html(style(), &mut html);
+        assert_eq!("color : red;  transition: opacity  1s ;", html);
+
+        provide_context(StyleFormat::Minified);
+        let mut html = String::new();
+        leptos::tachys::html::style::IntoStyle::to_html(style(), &mut html);
+        assert_eq!("color:red;transition:opacity 1s", html);
+    }
+}
@@ -0,0 +1,128 @@
+//! Hoisting of repeated inline styles into the [`StyleSheet`](crate::StyleSheet) from context during SSR.
+//!
+//! The first occurrence of a style is rendered inline. Identical styles rendered later are replaced by a marker
+//! declaration (e.g. `--ls-x: 1;`), with a rule selecting the marker added to the style sheet. [`IntoStyle`] only
+//! renders into the `style` attribute, so the rule selects elements by their `style` attribute instead of a class.
+//! On the client, hoisted styles are set inline again when hydrating, so the server and client must use the same
+//! [`StyleFormat`](crate::StyleFormat) and [`Prefixer`](crate::Prefixer). Without a style sheet in context, styles are
+//! always rendered inline.
+//!
+//! Until hydration, or permanently without JavaScript, hoisted declarations do not have inline priority. Their rule
+//! uses a doubled attribute selector, so it overrides single class rules like `.item`, but rules with at least the
+//! same specificity (e.g. `.list .item`) can override hoisted declarations they would not override inline.
+//!
+//! [`IntoStyle`]: leptos::tachys::html::style::IntoStyle
+
+use leptos::tachys::renderer::{types::Element, Rndr};
+
+use crate::{
+    sheet::{class_name, use_style_sheet},
+    style::Style,
+};
+
+/// Number of identical inline styles after which a style is hoisted.
+const HOIST_THRESHOLD: usize = 2;
+
+/// Prefix of marker declarations, used to skip styles which were not hoisted when hydrating.
+const MARKER_PREFIX: &str = "--ls-";
+
+/// Returns the marker declaration and rule for a style prepared with [`Style::prepare_for_rule`], or `None` if there
+/// are no declarations.
+fn hoisted_rule(style: &Style) -> Option<(String, String)> {
+    let declarations = style.to_rendered_string();
+    if declarations.trim().is_empty() {
+        return None;
+    }
+
+    let marker = format!("--{}", class_name(&declarations));
+    // The doubled selector raises the specificity above single class rules of the element, which inline
+    // declarations also override.
+    let selector = format!("[style*=\"{marker}:\"]");
+    let rule = format!("{selector}{selector} {{ {declarations} }}");
+    Some((format!("{marker}: 1;"), rule))
+}
+
+/// Counts `style` in the [`StyleSheet`](crate::StyleSheet) from context and, if it is repeated, writes its marker
+/// declaration to `buf` and adds its rule to the sheet.
+///
+/// Returns `false` if the style was not hoisted and should be rendered inline.
+pub(crate) fn hoist(style: &Style, buf: &mut String) -> bool {
+    let Some(sheet) = use_style_sheet() else {
+        return false;
+    };
+    let Some((marker, rule)) = hoisted_rule(&style.clone().prepare_for_rule()) else {
+        return false;
+    };
+    if sheet.count_occurrence(&marker) < HOIST_THRESHOLD {
+        return false;
+    }
+
+    sheet.insert(&marker, rule);
+    buf.push_str(&marker);
+    true
+}
+
+/// Replaces the marker declaration of a hoisted `style` rendered by the server with its inline declarations.
+pub(crate) fn restore(el: &Element, style: &Style) {
+    let Some(server) = el
+        .get_attribute("style")
+        .filter(|server| server.contains(MARKER_PREFIX))
+    else {
+        return;
+    };
+    let Some((marker, _)) = hoisted_rule(&style.clone().prepare_for_rule()) else {
+        return;
+    };
+
+    if server.contains(&marker) {
+        let rendered = style.clone().prepare_for_render().to_rendered_string();
+        Rndr::set_attribute(el, "style", &server.replace(&marker, &rendered));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos::prelude::{provide_context, Owner};
+
+    use super::*;
+    use crate::{sanitize::SanitizePolicy, sheet::provide_style_sheet};
+
+    fn render(style: &str) -> String {
+        let mut buf = String::new();
+        leptos::tachys::html::style::IntoStyle::to_html(Style::from(style), &mut buf);
+        buf
+    }
+
+    #[test]
+    fn test_hoist() {
+        let owner = Owner::new();
+        owner.set();
+
+        assert_eq!("color: red;", render("color: red;"));
+        assert_eq!("color: red;", render("color: red;"));
+
+        let sheet = provide_style_sheet();
+        assert_eq!("color: red;", render("color: red;"));
+
+        let marker = format!("--{}: 1;", class_name("color: red;"));
+        assert_eq!(marker, render("color: red;"));
+        assert_eq!("", render(""));
+        assert_eq!("", render(""));
+        assert_eq!(1, sheet.len());
+    }
+
+    #[test]
+    fn test_hoist_unsafe() {
+        let owner = Owner::new();
+        owner.set();
+
+        provide_context(SanitizePolicy::Allow);
+        let sheet = provide_style_sheet();
+
+        // Inline occurrences are rendered with the context policy, only the rule strips unsafe declarations.
+        let style = "color: red; background: url(a) } body { color: blue;";
+        assert_eq!(Style::from(style).to_string(), render(style));
+        assert!(render(style).starts_with(MARKER_PREFIX));
+        assert_eq!(1, sheet.to_css().matches('}').count());
+    }
+}
@@ -0,0 +1,223 @@
+use std::fmt::{self, Display};
+
+use indexmap::IndexMap;
+
+use crate::{parse::parse_declarations, value::StyleValue};
+
+/// How style hydration mismatches are handled, with the `debug` feature.
+///
+/// The policy can be set by providing it as context. Mismatches are always logged.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum HydrationPolicy {
+    /// Keep the server-rendered style until the next update, which replaces it as a whole.
+    #[default]
+    Warn,
+    /// Replace the server-rendered style with the client style.
+    Patch,
+}
+
+/// A difference between a server-rendered and a client style.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StyleDifference {
+    /// The property is only in the client style.
+    Missing { property: String, client: String },
+    /// The property is only in the server-rendered style.
+    Unexpected { property: String, server: String },
+    /// The property has different values.
+    Changed {
+        property: String,
+        server: String,
+        client: String,
+    },
+}
+
+impl Display for StyleDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { property, client } => {
+                write!(f, "`{property}` is missing, expected `{client}`")
+            }
+            Self::Unexpected { property, server } => {
+                write!(f, "`{property}: {server}` is unexpected")
+            }
+            Self::Changed {
+                property,
+                server,
+                client,
+            } => write!(f, "`{property}` is `{server}`, expected `{client}`"),
+        }
+    }
+}
+
+/// Differences between a server-rendered and a client style.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StyleMismatch {
+    pub differences: Vec<StyleDifference>,
+}
+
+impl StyleMismatch {
+    /// Compares two style attribute values, ignoring declaration order, whitespace and property name case.
+    ///
+    /// Returns `None` if both declare the same values.
+    pub fn compare(server: &str, client: &str) -> Option<Self> {
+        let server = normalize(server);
+        let client = normalize(client);
+
+        let mut differences = vec![];
+        for (property, client_value) in &client {
+            match server.get(property) {
+                None => differences.push(StyleDifference::Missing {
+                    property: property.clone(),
+                    client: client_value.to_string(),
+                }),
+                Some(server_value) if server_value != client_value => {
+                    differences.push(StyleDifference::Changed {
+                        property: property.clone(),
+                        server: server_value.to_string(),
+                        client: client_value.to_string(),
+                    })
+                }
+                _ => {}
+            }
+        }
+        for (property, server_value) in &server {
+            if !client.contains_key(property) {
+                differences.push(StyleDifference::Unexpected {
+                    property: property.clone(),
+                    server: server_value.to_string(),
+                });
+            }
+        }
+
+        (!differences.is_empty()).then_some(Self { differences })
+    }
+}
+
+impl Display for StyleMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.differences
+                .iter()
+                .map(StyleDifference::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Parses a style attribute value into its effective declarations.
+///
+/// Later declarations of a property replace earlier ones. Custom property names are case-sensitive.
+fn normalize(style: &str) -> IndexMap<String, StyleValue> {
+    parse_declarations(style)
+        .into_iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(property, value)| {
+            let property = if property.starts_with("--") {
+                property
+            } else {
+                property.to_ascii_lowercase()
+            };
+            let value = StyleValue::from(value);
+
+            (
+                property,
+                StyleValue {
+                    value: value
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                        .into(),
+                    important: value.important,
+                },
+            )
+        })
+        .collect()
+}
+
+/// Compares the server-rendered style attribute of `el` with the client style, logging mismatches and patching them
+/// according to the [`HydrationPolicy`] from context.
+///
+/// Returns the server-rendered style if a mismatch was kept.
+#[cfg(feature = "debug")]
+pub(crate) fn check_hydration(
+    el: &leptos::tachys::renderer::types::Element,
+    client: &str,
+) -> Option<String> {
+    use leptos::{logging::warn, prelude::use_context, tachys::renderer::Rndr};
+
+    let server = el.get_attribute("style").unwrap_or_default();
+
+    if let Some(mismatch) = StyleMismatch::compare(&server, client) {
+        warn!(
+            "style hydration mismatch on <{}>: {mismatch}",
+            el.tag_name().to_ascii_lowercase()
+        );
+
+        match use_context::<HydrationPolicy>().unwrap_or_default() {
+            HydrationPolicy::Warn => return Some(server),
+            HydrationPolicy::Patch => Rndr::set_attribute(el, "style", client),
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(None, StyleMismatch::compare("", ""));
+        assert_eq!(
+            None,
+            StyleMismatch::compare(
+                "color: red; margin:  0   auto;",
+                "MARGIN: 0 auto; color: red"
+            )
+        );
+        assert_eq!(
+            None,
+            StyleMismatch::compare("color: blue; color: red;", "color: red;")
+        );
+        assert_eq!(
+            None,
+            StyleMismatch::compare("color: red !important;", "color: red!IMPORTANT")
+        );
+
+        assert_eq!(
+            Some(StyleMismatch {
+                differences: vec![
+                    StyleDifference::Changed {
+                        property: "color".to_string(),
+                        server: "red".to_string(),
+                        client: "blue".to_string(),
+                    },
+                    StyleDifference::Missing {
+                        property: "padding".to_string(),
+                        client: "1rem".to_string(),
+                    },
+                    StyleDifference::Unexpected {
+                        property: "margin".to_string(),
+                        server: "0".to_string(),
+                    },
+                ],
+            }),
+            StyleMismatch::compare("color: red; margin: 0;", "color: blue; padding: 1rem;")
+        );
+
+        assert_eq!(
+            "`width` is `1px`, expected `1px !important`, `--Accent` is missing, expected `red`, \
+             `--accent: red` is unexpected",
+            StyleMismatch::compare(
+                "width: 1px; --accent: red",
+                "width: 1px !important; --Accent: red"
+            )
+            .unwrap()
+            .to_string()
+        );
+    }
+}
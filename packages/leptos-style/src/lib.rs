@@ -1,4 +1,50 @@
 //! Style for [Yew](https://yew.rs/) components.
+mod animation;
+mod class;
+#[cfg(feature = "node-ref")]
+mod computed;
+mod conditional;
+mod custom_property;
+mod format;
+#[cfg(feature = "hoist")]
+mod hoist;
+mod hydration;
+mod merge;
+mod parse;
+mod prefix;
+mod property;
+mod sanitize;
+mod sheet;
+mod shorthand;
 mod style;
+mod svg;
+#[cfg(feature = "tailwind")]
+mod tailwind;
+mod theme;
+mod validate;
+mod value;
+mod variant;
 
+pub use leptos_style_macro::{css, style};
+
+pub use crate::animation::*;
+pub use crate::class::*;
+#[cfg(feature = "node-ref")]
+pub use crate::computed::*;
+pub use crate::conditional::*;
+pub use crate::custom_property::*;
+pub use crate::format::*;
+pub use crate::hydration::*;
+pub use crate::merge::*;
+pub use crate::prefix::*;
+pub use crate::property::*;
+pub use crate::sanitize::*;
+pub use crate::sheet::*;
 pub use crate::style::*;
+pub use crate::svg::*;
+#[cfg(feature = "tailwind")]
+pub use crate::tailwind::*;
+pub use crate::theme::*;
+pub use crate::validate::*;
+pub use crate::value::*;
+pub use crate::variant::*;
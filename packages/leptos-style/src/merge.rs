@@ -0,0 +1,180 @@
+use crate::{
+    animation::transition_property,
+    parse::{split_components, split_list},
+    style::StyleMap,
+    value::StyleValue,
+};
+
+/// Properties with comma-separated list values, combined by [`MergeStrategy::Combine`].
+const COMMA_LIST_PROPERTIES: [&str; 6] = [
+    "transition",
+    "animation",
+    "box-shadow",
+    "text-shadow",
+    "background-image",
+    "will-change",
+];
+
+/// Properties with space-separated list values, combined by [`MergeStrategy::Combine`].
+const SPACE_LIST_PROPERTIES: [&str; 3] = ["transform", "filter", "backdrop-filter"];
+
+/// How two styles are merged by [`Style::merge`](crate::Style::merge).
+///
+/// In all strategies, a normal value never replaces an `!important` value.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum MergeStrategy {
+    /// Values from the other style override values in this style.
+    ///
+    /// `defaults.merge(style, MergeStrategy::Override)` is equivalent to `style.with_defaults(defaults)`.
+    #[default]
+    Override,
+    /// Values in this style are kept, the other style only adds missing properties.
+    Preserve,
+    /// Properties declared in the other style are removed from this style, regardless of their values.
+    Remove,
+    /// Like [`MergeStrategy::Override`], but list values (e.g. `transform`, `transition` and `box-shadow`) are
+    /// combined, with the items of this style first.
+    Combine,
+}
+
+/// Combines two list values, skipping `none`.
+///
+/// Transitions are deduplicated by property, so a transition in `other` replaces a transition of the same property.
+/// Other items are appended unchanged, since repeating them changes the result (e.g. two `rotate(45deg)`).
+pub(crate) fn combine_list_values(property: &str, value: &str, other: &str) -> Option<String> {
+    let separator = if COMMA_LIST_PROPERTIES.contains(&property) {
+        ", "
+    } else if SPACE_LIST_PROPERTIES.contains(&property) {
+        " "
+    } else {
+        return None;
+    };
+
+    if value == "none" {
+        return Some(other.to_string());
+    }
+    if other == "none" {
+        return Some(value.to_string());
+    }
+
+    let (items, other_items) = if separator == ", " {
+        (split_list(value), split_list(other))
+    } else {
+        (split_components(value), split_components(other))
+    };
+
+    let mut combined = items;
+    for other_item in other_items {
+        match combined.iter_mut().find(|item| {
+            property == "transition" && transition_property(item) == transition_property(other_item)
+        }) {
+            Some(item) => *item = other_item,
+            None => combined.push(other_item),
+        }
+    }
+
+    Some(combined.join(separator))
+}
+
+pub(crate) fn overrides(value: Option<&StyleValue>, other: Option<&StyleValue>) -> bool {
+    !value.is_some_and(|value| value.important) || other.is_some_and(|other| other.important)
+}
+
+pub(crate) fn merge_style_maps(
+    mut map: StyleMap,
+    other: StyleMap,
+    strategy: MergeStrategy,
+) -> StyleMap {
+    for (key, other_value) in other {
+        let value = map.get(&key).and_then(|value| value.as_ref());
+
+        match strategy {
+            MergeStrategy::Override => {
+                if overrides(value, other_value.as_ref()) {
+                    map.insert(key, other_value);
+                }
+            }
+            MergeStrategy::Preserve => {
+                if !map.contains_key(&key) || !overrides(other_value.as_ref(), value) {
+                    map.insert(key, other_value);
+                }
+            }
+            MergeStrategy::Remove => {
+                if !value.is_some_and(|value| value.important) {
+                    map.shift_remove(&key);
+                }
+            }
+            MergeStrategy::Combine => {
+                if !overrides(value, other_value.as_ref()) {
+                    continue;
+                }
+
+                let combined = value.zip(other_value.as_ref()).and_then(|(value, other)| {
+                    combine_list_values(&key, value, other).map(|combined| StyleValue {
+                        value: combined.into(),
+                        important: value.important || other.important,
+                    })
+                });
+
+                map.insert(key, combined.or(other_value));
+            }
+        }
+    }
+
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combine_list_values() {
+        for (property, value, other, expected) in [
+            ("color", "red", "blue", None),
+            (
+                "transition",
+                "opacity 0.2s",
+                "color 1s, opacity 0.2s",
+                Some("opacity 0.2s, color 1s"),
+            ),
+            (
+                "box-shadow",
+                "none",
+                "0 0 1px rgb(0, 0, 0)",
+                Some("0 0 1px rgb(0, 0, 0)"),
+            ),
+            (
+                "transform",
+                "translateX(1px)",
+                "rotate(1deg) scale(2)",
+                Some("translateX(1px) rotate(1deg) scale(2)"),
+            ),
+            ("filter", "blur(1px)", "none", Some("blur(1px)")),
+            (
+                "transform",
+                "rotate(45deg)",
+                "rotate(45deg)",
+                Some("rotate(45deg) rotate(45deg)"),
+            ),
+            (
+                "box-shadow",
+                "0 0 1px red",
+                "0 0 1px red",
+                Some("0 0 1px red, 0 0 1px red"),
+            ),
+            (
+                "transition",
+                "opacity 0.2s, color 1s",
+                "opacity 0.5s ease",
+                Some("opacity 0.5s ease, color 1s"),
+            ),
+        ] {
+            assert_eq!(
+                expected.map(str::to_string),
+                combine_list_values(property, value, other),
+                "{property}: {value} + {other}",
+            );
+        }
+    }
+}
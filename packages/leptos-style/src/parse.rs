@@ -0,0 +1,294 @@
+use std::str::CharIndices;
+
+/// A character scanned by [`Scanner`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Scanned {
+    pub(crate) index: usize,
+    pub(crate) char: char,
+    /// Whether the character is inside quotes or escaped, so it is not syntax. Opening quotes and backslashes are
+    /// not literal, closing quotes are.
+    pub(crate) literal: bool,
+    /// The parenthesis nesting before the character.
+    pub(crate) depth: usize,
+}
+
+impl Scanned {
+    /// Returns whether the character is `char` outside of quotes, escapes and parentheses.
+    pub(crate) fn is_top_level(&self, char: char) -> bool {
+        !self.literal && self.depth == 0 && self.char == char
+    }
+}
+
+/// Scans CSS text, tracking quotes, escapes and parenthesis nesting.
+pub(crate) struct Scanner<'a> {
+    chars: CharIndices<'a>,
+    depth: usize,
+    quote: Option<char>,
+    escaped: bool,
+    unbalanced: bool,
+}
+
+impl<'a> Scanner<'a> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        Self {
+            chars: input.char_indices(),
+            depth: 0,
+            quote: None,
+            escaped: false,
+            unbalanced: false,
+        }
+    }
+
+    /// Returns whether all quotes, escapes and parentheses scanned so far are closed.
+    pub(crate) fn is_balanced(&self) -> bool {
+        !self.unbalanced && self.depth == 0 && self.quote.is_none() && !self.escaped
+    }
+}
+
+impl Iterator for Scanner<'_> {
+    type Item = Scanned;
+
+    fn next(&mut self) -> Option<Scanned> {
+        let (index, char) = self.chars.next()?;
+        let scanned = Scanned {
+            index,
+            char,
+            literal: self.escaped || self.quote.is_some(),
+            depth: self.depth,
+        };
+
+        if self.escaped {
+            self.escaped = false;
+            return Some(scanned);
+        }
+
+        match (self.quote, char) {
+            (_, '\\') => self.escaped = true,
+            (Some(open), char) if char == open => self.quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => self.quote = Some(char),
+            (None, '(') => self.depth += 1,
+            (None, ')') => match self.depth.checked_sub(1) {
+                Some(depth) => self.depth = depth,
+                None => self.unbalanced = true,
+            },
+            _ => {}
+        }
+
+        Some(scanned)
+    }
+}
+
+/// Splits CSS text at top-level occurrences of `separator`, see [`Scanned::is_top_level`].
+fn split_top_level(input: &str, separator: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut start = 0;
+
+    for scanned in Scanner::new(input) {
+        if scanned.is_top_level(separator) {
+            parts.push(&input[start..scanned.index]);
+            start = scanned.index + separator.len_utf8();
+        }
+    }
+    parts.push(&input[start..]);
+
+    parts
+}
+
+/// Splits a CSS declaration block (e.g. `"color: red; padding: 1rem;"`) into `(property, value)` pairs.
+///
+/// Semicolons inside parentheses or quotes (e.g. `url("a;b")`) do not end a declaration.
+/// Declarations without a colon or with an empty property name are skipped.
+pub(crate) fn parse_declarations(input: &str) -> Vec<(String, String)> {
+    split_top_level(input, ';')
+        .into_iter()
+        .filter_map(parse_declaration)
+        .collect()
+}
+
+/// Returns whether every non-empty part of a declaration block is a declaration, i.e. [`parse_declarations`] skips
+/// nothing.
+pub(crate) fn is_declaration_list(input: &str) -> bool {
+    split_top_level(input, ';').into_iter().all(|declaration| {
+        declaration.trim().is_empty() || parse_declaration(declaration).is_some()
+    })
+}
+
+/// Splits a value into whitespace-separated components.
+///
+/// Whitespace inside parentheses or quotes (e.g. `calc(1px + 2px)`) does not split a component.
+pub(crate) fn split_components(input: &str) -> Vec<&str> {
+    let mut components = vec![];
+    let mut start: Option<usize> = None;
+
+    for scanned in Scanner::new(input) {
+        if !scanned.literal && scanned.depth == 0 && scanned.char.is_whitespace() {
+            if let Some(start) = start.take() {
+                components.push(&input[start..scanned.index]);
+            }
+        } else {
+            start.get_or_insert(scanned.index);
+        }
+    }
+    if let Some(start) = start {
+        components.push(&input[start..]);
+    }
+
+    components
+}
+
+/// Splits a comma-separated list (e.g. a `transition` value) into trimmed items.
+///
+/// Commas inside parentheses or quotes (e.g. `rgb(0, 0, 0)`) do not split an item.
+pub(crate) fn split_list(input: &str) -> Vec<&str> {
+    split_top_level(input, ',')
+        .into_iter()
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// Collapses whitespace in a value to single spaces, with a single space after commas (none if `minified`) and none
+/// inside parentheses.
+///
+/// Whitespace inside quotes (e.g. `"Open  Sans"`) is kept.
+pub(crate) fn normalize_whitespace(input: &str, minified: bool) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut space = false;
+
+    for Scanned { char, literal, .. } in Scanner::new(input.trim()) {
+        if literal {
+            output.push(char);
+            continue;
+        }
+
+        if char.is_whitespace() {
+            space = true;
+            continue;
+        }
+        if space && !output.ends_with(['(', ',', ' ']) && !matches!(char, ')' | ',') {
+            output.push(' ');
+        }
+        space = false;
+
+        output.push(char);
+        if char == ',' && !minified {
+            output.push(' ');
+        }
+    }
+
+    output.truncate(output.trim_end().len());
+    output
+}
+
+fn parse_declaration(input: &str) -> Option<(String, String)> {
+    let (property, value) = input.split_once(':')?;
+    let property = property.trim();
+
+    (!property.is_empty()).then(|| (property.to_string(), value.trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scanner() {
+        let literal = |input: &str| {
+            Scanner::new(input)
+                .map(|scanned| if scanned.literal { '1' } else { '0' })
+                .collect::<String>()
+        };
+        assert_eq!("00011100", literal("a \"b;\" c"));
+        assert_eq!("0100", literal("\\;;x"));
+
+        let depths = Scanner::new("a(b)")
+            .map(|scanned| scanned.depth)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![0, 0, 1, 1], depths);
+
+        let is_balanced = |input: &str| {
+            let mut scanner = Scanner::new(input);
+            scanner.by_ref().for_each(drop);
+            scanner.is_balanced()
+        };
+        assert!(is_balanced("url(\"a)\") 'b(' \\)"));
+        assert!(!is_balanced("calc(1px))"));
+        assert!(!is_balanced("'a"));
+        assert!(!is_balanced("a\\"));
+    }
+
+    #[test]
+    fn test_split_components() {
+        assert_eq!(Vec::<&str>::new(), split_components("  "));
+        assert_eq!(
+            vec!["1px", "solid", "red"],
+            split_components(" 1px  solid\tred ")
+        );
+        assert_eq!(
+            vec!["calc(1px + 2px)", "\"Open Sans\"", "rgb(0 0 0)"],
+            split_components("calc(1px + 2px) \"Open Sans\" rgb(0 0 0)"),
+        );
+    }
+
+    #[test]
+    fn test_split_list() {
+        assert_eq!(Vec::<&str>::new(), split_list(" "));
+        assert_eq!(
+            vec!["opacity 0.2s", "color 1s cubic-bezier(0, 0, 1, 1)"],
+            split_list("opacity 0.2s, color 1s cubic-bezier(0, 0, 1, 1)"),
+        );
+    }
+
+    #[test]
+    fn test_normalize_whitespace() {
+        for (input, expected, expected_minified) in [
+            ("  red ", "red", "red"),
+            ("1px\n  solid\tred", "1px solid red", "1px solid red"),
+            ("rgb( 0 ,0,  0 )", "rgb(0, 0, 0)", "rgb(0,0,0)"),
+            ("calc( 1px  +  2px )", "calc(1px + 2px)", "calc(1px + 2px)"),
+            (
+                "\"Open  Sans\" ,  sans-serif",
+                "\"Open  Sans\", sans-serif",
+                "\"Open  Sans\",sans-serif",
+            ),
+            ("'a\\'  b'  c", "'a\\'  b' c", "'a\\'  b' c"),
+        ] {
+            assert_eq!(expected, normalize_whitespace(input, false), "{input}");
+            assert_eq!(
+                expected_minified,
+                normalize_whitespace(input, true),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_declarations() {
+        assert_eq!(Vec::<(String, String)>::new(), parse_declarations(""));
+
+        assert_eq!(
+            vec![
+                ("margin".to_string(), "1rem".to_string()),
+                ("padding".to_string(), "0.5rem".to_string()),
+            ],
+            parse_declarations("margin: 1rem; padding: 0.5rem;"),
+        );
+
+        assert_eq!(
+            vec![
+                (
+                    "background".to_string(),
+                    "url(\"a;b.png\") no-repeat".to_string()
+                ),
+                ("--x".to_string(), "var(--y, 1px)".to_string()),
+            ],
+            parse_declarations("background: url(\"a;b.png\") no-repeat;--x:var(--y, 1px)"),
+        );
+
+        assert_eq!(
+            vec![("color".to_string(), "red".to_string())],
+            parse_declarations(";; invalid; : empty; color: red"),
+        );
+    }
+}
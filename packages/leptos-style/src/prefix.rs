@@ -0,0 +1,267 @@
+use crate::value::StyleValue;
+
+/// Browser engines that vendor prefixes can be generated for.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PrefixTarget {
+    /// Chrome, Edge and other Chromium-based browsers.
+    Chromium,
+    Firefox,
+    Safari,
+}
+
+use PrefixTarget::*;
+
+/// Properties that need a vendor prefix for some targets.
+const PROPERTY_PREFIXES: [(&str, &[(PrefixTarget, &str)]); 20] = [
+    (
+        "appearance",
+        &[
+            (Chromium, "-webkit-"),
+            (Firefox, "-moz-"),
+            (Safari, "-webkit-"),
+        ],
+    ),
+    ("backdrop-filter", &[(Safari, "-webkit-")]),
+    (
+        "background-clip",
+        &[(Chromium, "-webkit-"), (Safari, "-webkit-")],
+    ),
+    (
+        "box-decoration-break",
+        &[(Chromium, "-webkit-"), (Safari, "-webkit-")],
+    ),
+    ("hyphens", &[(Safari, "-webkit-")]),
+    ("initial-letter", &[(Safari, "-webkit-")]),
+    (
+        "line-clamp",
+        &[
+            (Chromium, "-webkit-"),
+            (Firefox, "-webkit-"),
+            (Safari, "-webkit-"),
+        ],
+    ),
+    ("mask", &[(Chromium, "-webkit-"), (Safari, "-webkit-")]),
+    ("mask-clip", &[(Chromium, "-webkit-"), (Safari, "-webkit-")]),
+    (
+        "mask-composite",
+        &[(Chromium, "-webkit-"), (Safari, "-webkit-")],
+    ),
+    (
+        "mask-image",
+        &[(Chromium, "-webkit-"), (Safari, "-webkit-")],
+    ),
+    (
+        "mask-origin",
+        &[(Chromium, "-webkit-"), (Safari, "-webkit-")],
+    ),
+    (
+        "mask-position",
+        &[(Chromium, "-webkit-"), (Safari, "-webkit-")],
+    ),
+    (
+        "mask-repeat",
+        &[(Chromium, "-webkit-"), (Safari, "-webkit-")],
+    ),
+    ("mask-size", &[(Chromium, "-webkit-"), (Safari, "-webkit-")]),
+    (
+        "print-color-adjust",
+        &[(Chromium, "-webkit-"), (Safari, "-webkit-")],
+    ),
+    ("tab-size", &[(Firefox, "-moz-")]),
+    (
+        "text-size-adjust",
+        &[
+            (Chromium, "-webkit-"),
+            (Firefox, "-moz-"),
+            (Safari, "-webkit-"),
+        ],
+    ),
+    (
+        "text-stroke",
+        &[(Chromium, "-webkit-"), (Safari, "-webkit-")],
+    ),
+    ("user-select", &[(Firefox, "-moz-"), (Safari, "-webkit-")]),
+];
+
+/// Properties accepting the `stretch` sizing keyword.
+const SIZING_PROPERTIES: [&str; 12] = [
+    "width",
+    "height",
+    "min-width",
+    "min-height",
+    "max-width",
+    "max-height",
+    "inline-size",
+    "block-size",
+    "min-inline-size",
+    "min-block-size",
+    "max-inline-size",
+    "max-block-size",
+];
+
+/// Returns the prefixed alternatives of a value for some targets.
+fn value_prefixes(property: &str, value: &str) -> &'static [(PrefixTarget, &'static str)] {
+    match (property, value.to_ascii_lowercase().as_str()) {
+        ("position", "sticky") => &[(Safari, "-webkit-sticky")],
+        (property, "stretch" | "fill-available") if SIZING_PROPERTIES.contains(&property) => &[
+            (Chromium, "-webkit-fill-available"),
+            (Firefox, "-moz-available"),
+            (Safari, "-webkit-fill-available"),
+        ],
+        _ => &[],
+    }
+}
+
+/// Adds vendor-prefixed declarations for the configured targets when styles are rendered.
+///
+/// Prefixing is opt-in, enable it by providing a prefixer as context:
+///
+/// ```
+/// use leptos::prelude::provide_context;
+/// use leptos_style::{PrefixTarget, Prefixer};
+///
+/// # let owner = leptos::prelude::Owner::new();
+/// # owner.set();
+/// provide_context(Prefixer::new([PrefixTarget::Safari]));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Prefixer {
+    pub targets: Vec<PrefixTarget>,
+}
+
+impl Prefixer {
+    pub fn new<I: IntoIterator<Item = PrefixTarget>>(targets: I) -> Self {
+        Self {
+            targets: targets.into_iter().collect(),
+        }
+    }
+
+    /// Creates a prefixer for all targets.
+    pub fn all() -> Self {
+        Self::new([Chromium, Firefox, Safari])
+    }
+
+    /// Returns the prefixes needed for the targets, without duplicates.
+    fn prefixes<'a>(&self, prefixes: &[(PrefixTarget, &'a str)]) -> Vec<&'a str> {
+        let mut result: Vec<&str> = vec![];
+        for (target, prefix) in prefixes {
+            if self.targets.contains(target) && !result.contains(prefix) {
+                result.push(prefix);
+            }
+        }
+        result
+    }
+
+    /// Returns the prefixed names of a property for the targets.
+    pub fn prefixed_properties(&self, property: &str) -> Vec<String> {
+        PROPERTY_PREFIXES
+            .iter()
+            .find(|(name, _)| *name == property)
+            .map(|(_, prefixes)| {
+                self.prefixes(prefixes)
+                    .into_iter()
+                    .map(|prefix| format!("{prefix}{property}"))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the declarations to render for a declaration: the prefixed declarations followed by the declaration
+    /// itself, so browsers supporting the standard syntax use it.
+    pub fn prefix_declaration(
+        &self,
+        property: &str,
+        value: &StyleValue,
+    ) -> Vec<(String, StyleValue)> {
+        let mut declarations = self
+            .prefixed_properties(property)
+            .into_iter()
+            .map(|property| (property, value.clone()))
+            .collect::<Vec<_>>();
+
+        for prefixed_value in self.prefixes(value_prefixes(property, value)) {
+            declarations.push((
+                property.to_string(),
+                StyleValue {
+                    value: prefixed_value.into(),
+                    important: value.important,
+                },
+            ));
+        }
+
+        declarations.push((property.to_string(), value.clone()));
+        declarations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(prefixer: &Prefixer, property: &str, value: &str) -> String {
+        prefixer
+            .prefix_declaration(property, &StyleValue::from(value.to_string()))
+            .into_iter()
+            .map(|(property, value)| format!("{property}: {value};"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_prefix_declaration() {
+        let all = Prefixer::all();
+        let safari = Prefixer::new([Safari]);
+        let none = Prefixer::default();
+
+        for (prefixer, property, value, expected) in [
+            (&all, "color", "red", "color: red;"),
+            (&none, "user-select", "none", "user-select: none;"),
+            (
+                &all,
+                "user-select",
+                "none",
+                "-moz-user-select: none; -webkit-user-select: none; user-select: none;",
+            ),
+            (
+                &safari,
+                "user-select",
+                "none",
+                "-webkit-user-select: none; user-select: none;",
+            ),
+            (
+                &all,
+                "appearance",
+                "none !important",
+                "-webkit-appearance: none !important; -moz-appearance: none !important; appearance: none !important;",
+            ),
+            (
+                &safari,
+                "backdrop-filter",
+                "blur(4px)",
+                "-webkit-backdrop-filter: blur(4px); backdrop-filter: blur(4px);",
+            ),
+            (
+                &Prefixer::new([Chromium]),
+                "mask-image",
+                "url(mask.svg)",
+                "-webkit-mask-image: url(mask.svg); mask-image: url(mask.svg);",
+            ),
+            (
+                &all,
+                "width",
+                "stretch",
+                "width: -webkit-fill-available; width: -moz-available; width: stretch;",
+            ),
+            (&all, "width", "100%", "width: 100%;"),
+            (
+                &safari,
+                "position",
+                "sticky",
+                "position: -webkit-sticky; position: sticky;",
+            ),
+            (&safari, "position", "fixed", "position: fixed;"),
+        ] {
+            assert_eq!(expected, render(prefixer, property, value), "{property}: {value}");
+        }
+    }
+}
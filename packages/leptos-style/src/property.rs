@@ -0,0 +1,140 @@
+use std::borrow::Cow;
+
+use crate::custom_property::is_custom_property;
+
+/// Properties that accept unitless numbers, as in React.
+const UNITLESS_PROPERTIES: [&str; 44] = [
+    "animation-iteration-count",
+    "aspect-ratio",
+    "border-image-outset",
+    "border-image-slice",
+    "border-image-width",
+    "box-flex",
+    "box-flex-group",
+    "box-ordinal-group",
+    "column-count",
+    "columns",
+    "fill-opacity",
+    "flex",
+    "flex-grow",
+    "flex-negative",
+    "flex-order",
+    "flex-positive",
+    "flex-shrink",
+    "flood-opacity",
+    "font-weight",
+    "grid-area",
+    "grid-column",
+    "grid-column-end",
+    "grid-column-span",
+    "grid-column-start",
+    "grid-row",
+    "grid-row-end",
+    "grid-row-span",
+    "grid-row-start",
+    "line-clamp",
+    "line-height",
+    "opacity",
+    "order",
+    "orphans",
+    "scale",
+    "stop-opacity",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-miterlimit",
+    "stroke-opacity",
+    "stroke-width",
+    "tab-size",
+    "widows",
+    "z-index",
+    "zoom",
+];
+
+/// Converts a camelCase property name (e.g. `backgroundColor` or `WebkitTransform`) to kebab-case.
+///
+/// Kebab-case names and custom properties are returned unchanged. As in React, the `ms` vendor prefix is written in
+/// lowercase (e.g. `msTransform` becomes `-ms-transform`).
+pub fn normalize_property(name: &str) -> Cow<'_, str> {
+    if is_custom_property(name) || !name.contains(|char: char| char.is_ascii_uppercase()) {
+        return Cow::Borrowed(name);
+    }
+
+    let mut normalized = String::with_capacity(name.len() + 4);
+    for char in name.chars() {
+        if char.is_ascii_uppercase() {
+            normalized.push('-');
+            normalized.push(char.to_ascii_lowercase());
+        } else {
+            normalized.push(char);
+        }
+    }
+
+    if normalized.starts_with("ms-") {
+        normalized.insert(0, '-');
+    }
+
+    Cow::Owned(normalized)
+}
+
+/// Returns whether a property accepts unitless numbers, as in React. `property` is normalized first.
+pub fn is_unitless_property(property: &str) -> bool {
+    let property = normalize_property(property);
+    let property = property
+        .strip_prefix('-')
+        .and_then(|property| property.split_once('-'))
+        .map_or(property.as_ref(), |(_, property)| property);
+
+    UNITLESS_PROPERTIES.binary_search(&property).is_ok()
+}
+
+/// Formats a number for a property, appending `px` if the property is dimensional, as in React.
+///
+/// Zero, custom properties and unitless properties (e.g. `opacity` and `z-index`) do not get a unit.
+pub fn number_value(property: &str, number: f64) -> String {
+    if number == 0.0 || is_custom_property(property) || is_unitless_property(property) {
+        number.to_string()
+    } else {
+        format!("{number}px")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_property() {
+        for (name, expected) in [
+            ("color", "color"),
+            ("background-color", "background-color"),
+            ("backgroundColor", "background-color"),
+            ("borderTopLeftRadius", "border-top-left-radius"),
+            ("WebkitTransform", "-webkit-transform"),
+            ("MozAppearance", "-moz-appearance"),
+            ("msTransform", "-ms-transform"),
+            ("-webkit-line-clamp", "-webkit-line-clamp"),
+            ("--myColor", "--myColor"),
+        ] {
+            assert_eq!(expected, normalize_property(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_number_value() {
+        assert!(UNITLESS_PROPERTIES.windows(2).all(|pair| pair[0] < pair[1]));
+
+        for (property, number, expected) in [
+            ("width", 100.0, "100px"),
+            ("marginTop", -1.5, "-1.5px"),
+            ("height", 0.0, "0"),
+            ("opacity", 0.5, "0.5"),
+            ("zIndex", 10.0, "10"),
+            ("lineHeight", 1.5, "1.5"),
+            ("WebkitLineClamp", 3.0, "3"),
+            ("flex-grow", 1.0, "1"),
+            ("--size", 4.0, "4"),
+        ] {
+            assert_eq!(expected, number_value(property, number), "{property}");
+        }
+    }
+}
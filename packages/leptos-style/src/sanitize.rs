@@ -0,0 +1,363 @@
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt::{self, Display},
+};
+
+use crate::parse::{Scanned, Scanner};
+
+/// Values that can execute script in some browsers and are never allowed.
+const FORBIDDEN_PATTERNS: [&str; 5] = [
+    "expression(",
+    "javascript:",
+    "vbscript:",
+    "-moz-binding",
+    "behavior:",
+];
+
+/// How unsafe declarations are handled when a style is sanitized.
+///
+/// By default, unsafe declarations are removed when rendering. A policy used during rendering can be set by providing
+/// it as context:
+///
+/// ```
+/// use leptos::prelude::provide_context;
+/// use leptos_style::SanitizePolicy;
+///
+/// # let owner = leptos::prelude::Owner::new();
+/// # owner.set();
+/// provide_context(SanitizePolicy::Escape);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum SanitizePolicy {
+    /// Keep all declarations without checking them.
+    Allow,
+    /// Remove unsafe declarations.
+    #[default]
+    Strip,
+    /// Escape unsafe characters so they cannot end the declaration or attribute.
+    /// Declarations with forbidden values are removed.
+    Escape,
+    /// Reject the style.
+    Error,
+}
+
+/// Why a declaration was considered unsafe.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SanitizeReason {
+    /// The property name is not a valid CSS identifier.
+    InvalidProperty,
+    /// The value contains a character that can end the declaration or attribute.
+    UnsafeCharacter(char),
+    /// The value has unbalanced quotes or parentheses.
+    Unbalanced,
+    /// The value contains a pattern that can execute script.
+    Forbidden(&'static str),
+}
+
+impl Display for SanitizeReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidProperty => write!(f, "invalid property name"),
+            Self::UnsafeCharacter(char) => write!(f, "unsafe character {char:?}"),
+            Self::Unbalanced => write!(f, "unbalanced quotes or parentheses"),
+            Self::Forbidden(pattern) => write!(f, "forbidden pattern `{pattern}`"),
+        }
+    }
+}
+
+/// An unsafe declaration found by [`SanitizePolicy::Error`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SanitizeError {
+    pub property: String,
+    pub value: String,
+    pub reason: SanitizeReason,
+}
+
+impl Display for SanitizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unsafe style declaration `{}: {}`: {}",
+            self.property, self.value, self.reason
+        )
+    }
+}
+
+impl Error for SanitizeError {}
+
+/// Returns whether a property name is a CSS identifier. Only hex escapes (e.g. `\00002e`) are allowed, as used by
+/// [`Token::custom_property`](crate::Token::custom_property).
+fn is_valid_property(property: &str) -> bool {
+    let mut chars = property.chars().peekable();
+    let mut valid = true;
+    while let Some(char) = chars.next() {
+        valid &= match char {
+            '\\' => chars.peek().is_some_and(char::is_ascii_hexdigit),
+            char => char.is_ascii_alphanumeric() || matches!(char, '-' | '_') || !char.is_ascii(),
+        };
+    }
+
+    valid && !property.is_empty() && property != "-" && property != "--"
+}
+
+/// Decodes CSS escapes (e.g. `\73` or `\:`), so obfuscated patterns can be detected.
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        if char != '\\' {
+            result.push(char);
+            continue;
+        }
+
+        let mut hex = String::new();
+        while hex.len() < 6 && chars.peek().is_some_and(char::is_ascii_hexdigit) {
+            hex.extend(chars.next());
+        }
+
+        if hex.is_empty() {
+            result.extend(chars.next());
+        } else {
+            if chars.peek().is_some_and(|char| char.is_ascii_whitespace()) {
+                chars.next();
+            }
+            result.extend(u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32));
+        }
+    }
+
+    result
+}
+
+fn check_value(value: &str) -> Option<SanitizeReason> {
+    let mut scanner = Scanner::new(value);
+
+    for Scanned {
+        char,
+        literal,
+        depth,
+        ..
+    } in scanner.by_ref()
+    {
+        if matches!(char, '<' | '>' | '\n' | '\r' | '\0') {
+            return Some(SanitizeReason::UnsafeCharacter(char));
+        }
+
+        match char {
+            _ if literal => {}
+            ')' if depth == 0 => return Some(SanitizeReason::Unbalanced),
+            ';' | '{' | '}' => return Some(SanitizeReason::UnsafeCharacter(char)),
+            _ => {}
+        }
+    }
+
+    if !scanner.is_balanced() {
+        return Some(SanitizeReason::Unbalanced);
+    }
+
+    let normalized: String = unescape(value)
+        .chars()
+        .filter(|char| !char.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+
+    FORBIDDEN_PATTERNS
+        .into_iter()
+        .find(|pattern| normalized.contains(pattern))
+        .map(SanitizeReason::Forbidden)
+}
+
+/// Escapes every character that could end a declaration or attribute.
+fn escape(input: &str, is_property: bool) -> String {
+    input
+        .chars()
+        .map(|char| {
+            let safe = if is_property {
+                char.is_ascii_alphanumeric() || matches!(char, '-' | '_') || !char.is_ascii()
+            } else {
+                !matches!(
+                    char,
+                    ';' | '{'
+                        | '}'
+                        | '<'
+                        | '>'
+                        | '"'
+                        | '\''
+                        | '('
+                        | ')'
+                        | '\\'
+                        | '\n'
+                        | '\r'
+                        | '\0'
+                )
+            };
+
+            if safe {
+                char.to_string()
+            } else {
+                format!("\\{:x} ", char as u32)
+            }
+        })
+        .collect()
+}
+
+/// A sanitized property and value.
+type Declaration<'a> = (Cow<'a, str>, Cow<'a, str>);
+
+/// Sanitizes a single declaration.
+///
+/// Returns `Ok(None)` if the declaration should be removed. Valid declarations are borrowed, not copied.
+pub(crate) fn sanitize_declaration<'a>(
+    property: &'a str,
+    value: &'a str,
+    policy: SanitizePolicy,
+) -> Result<Option<Declaration<'a>>, SanitizeError> {
+    if policy == SanitizePolicy::Allow {
+        return Ok(Some((Cow::Borrowed(property), Cow::Borrowed(value))));
+    }
+
+    let valid_property = is_valid_property(property);
+    let value_reason = check_value(value);
+
+    let reason = match (valid_property, value_reason) {
+        (true, None) => return Ok(Some((Cow::Borrowed(property), Cow::Borrowed(value)))),
+        (false, None) => SanitizeReason::InvalidProperty,
+        (_, Some(reason)) => reason,
+    };
+
+    match (reason, policy) {
+        (reason, SanitizePolicy::Error) => Err(SanitizeError {
+            property: property.to_string(),
+            value: value.to_string(),
+            reason,
+        }),
+        (SanitizeReason::Forbidden(_), _) | (_, SanitizePolicy::Allow | SanitizePolicy::Strip) => {
+            Ok(None)
+        }
+        (_, SanitizePolicy::Escape) => Ok(Some((
+            if valid_property {
+                Cow::Borrowed(property)
+            } else {
+                Cow::Owned(escape(property, true))
+            },
+            if value_reason.is_some() {
+                Cow::Owned(escape(value, false))
+            } else {
+                Cow::Borrowed(value)
+            },
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sanitize(
+        property: &str,
+        value: &str,
+        policy: SanitizePolicy,
+    ) -> Result<Option<String>, SanitizeReason> {
+        sanitize_declaration(property, value, policy)
+            .map(|declaration| declaration.map(|(property, value)| format!("{property}: {value}")))
+            .map_err(|error| error.reason)
+    }
+
+    #[test]
+    fn test_safe() {
+        for (property, value) in [
+            ("color", "red"),
+            ("--x", "var(--y, 1px)"),
+            ("font-family", "\"Open Sans\", sans-serif"),
+            ("background", "url(\"a;b.png\")"),
+            ("content", "'\\201C'"),
+            ("--spacing-1\\00002e5", "0.375rem"),
+        ] {
+            for policy in [
+                SanitizePolicy::Strip,
+                SanitizePolicy::Escape,
+                SanitizePolicy::Error,
+            ] {
+                assert_eq!(
+                    Ok(Some(format!("{property}: {value}"))),
+                    sanitize(property, value, policy),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_unsafe() {
+        assert_eq!(
+            Ok(None),
+            sanitize("color", "red; position: fixed", SanitizePolicy::Strip)
+        );
+        assert_eq!(
+            Ok(Some("color: red\\3b  position: fixed".to_string())),
+            sanitize("color", "red; position: fixed", SanitizePolicy::Escape)
+        );
+        assert_eq!(
+            Err(SanitizeReason::UnsafeCharacter(';')),
+            sanitize("color", "red; position: fixed", SanitizePolicy::Error)
+        );
+
+        assert_eq!(
+            Ok(Some("color: red\\22  onclick=alert\\28 1\\29 ".to_string())),
+            sanitize("color", "red\" onclick=alert(1)", SanitizePolicy::Escape)
+        );
+        assert_eq!(
+            Err(SanitizeReason::Unbalanced),
+            sanitize("color", "red\"", SanitizePolicy::Error)
+        );
+        assert_eq!(
+            Err(SanitizeReason::Unbalanced),
+            sanitize("width", "calc(1px))", SanitizePolicy::Error)
+        );
+        assert_eq!(
+            Err(SanitizeReason::UnsafeCharacter('<')),
+            sanitize("color", "</style>", SanitizePolicy::Error)
+        );
+
+        assert_eq!(
+            Ok(Some("color\\3a red\\3b x: 1".to_string())),
+            sanitize("color:red;x", "1", SanitizePolicy::Escape)
+        );
+        assert_eq!(
+            Err(SanitizeReason::InvalidProperty),
+            sanitize("color:red;x", "1", SanitizePolicy::Error)
+        );
+        assert_eq!(
+            Err(SanitizeReason::InvalidProperty),
+            sanitize("--x\\;", "1", SanitizePolicy::Error)
+        );
+    }
+
+    #[test]
+    fn test_forbidden() {
+        assert_eq!(
+            Ok(None),
+            sanitize("width", "expression(alert(1))", SanitizePolicy::Escape)
+        );
+        assert_eq!(
+            Err(SanitizeReason::Forbidden("javascript:")),
+            sanitize(
+                "background",
+                "url(JavaScript:alert(1))",
+                SanitizePolicy::Error
+            )
+        );
+        assert_eq!(
+            Err(SanitizeReason::Forbidden("javascript:")),
+            sanitize(
+                "background",
+                "url(java\\73 cript:alert(1))",
+                SanitizePolicy::Error
+            )
+        );
+        assert_eq!(
+            Ok(None),
+            sanitize("x;y", "expression(alert(1))", SanitizePolicy::Escape)
+        );
+    }
+}
@@ -0,0 +1,229 @@
+#[cfg(feature = "hoist")]
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use indexmap::IndexMap;
+use leptos::prelude::{provide_context, use_context};
+
+use crate::style::Style;
+
+/// Attribute marking `<style>` elements rendered by [`StyleSheet`].
+pub const STYLE_SHEET_ATTRIBUTE: &str = "data-leptos-style";
+
+/// FNV-1a, which is stable across platforms and compiler versions, unlike [`std::hash::DefaultHasher`].
+fn hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Scoped style rules collected during rendering.
+///
+/// Rules are deduplicated by class name. Provide a style sheet with [`provide_style_sheet`] during SSR and render
+/// [`StyleSheet::to_html`] into the document head after the app has been rendered.
+#[derive(Clone, Debug, Default)]
+pub struct StyleSheet {
+    rules: Arc<Mutex<IndexMap<String, String>>>,
+    /// Rendered inline styles by marker declaration, counted to detect repeated styles.
+    #[cfg(feature = "hoist")]
+    occurrences: Arc<Mutex<HashMap<String, usize>>>,
+}
+
+impl StyleSheet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule for `class`, returning `false` if the style sheet already contains it.
+    pub fn insert(&self, class: &str, rule: String) -> bool {
+        let mut rules = self
+            .rules
+            .lock()
+            .expect("style sheet lock should not be poisoned");
+        if rules.contains_key(class) {
+            false
+        } else {
+            rules.insert(class.to_string(), rule);
+            true
+        }
+    }
+
+    pub fn contains(&self, class: &str) -> bool {
+        self.rules
+            .lock()
+            .expect("style sheet lock should not be poisoned")
+            .contains_key(class)
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules
+            .lock()
+            .expect("style sheet lock should not be poisoned")
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Counts a rendered inline style with the marker declaration `marker`, returning the number of occurrences.
+    #[cfg(feature = "hoist")]
+    pub(crate) fn count_occurrence(&self, marker: &str) -> usize {
+        let mut occurrences = self
+            .occurrences
+            .lock()
+            .expect("style sheet lock should not be poisoned");
+        let count = occurrences.entry(marker.to_string()).or_default();
+        *count += 1;
+        *count
+    }
+
+    /// Returns the collected rules, in insertion order.
+    pub fn to_css(&self) -> String {
+        self.rules
+            .lock()
+            .expect("style sheet lock should not be poisoned")
+            .values()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns a `<style>` element containing the collected rules, or an empty string if there are none.
+    pub fn to_html(&self) -> String {
+        if self.is_empty() {
+            return String::new();
+        }
+
+        // Declarations in rules never contain `<` after sanitizing, this only guards hand-written rules.
+        format!(
+            "<style {STYLE_SHEET_ATTRIBUTE}>{}</style>",
+            self.to_css().replace("</", "<\\/")
+        )
+    }
+}
+
+/// Provides a new [`StyleSheet`] to collect scoped style rules.
+pub fn provide_style_sheet() -> StyleSheet {
+    let sheet = StyleSheet::new();
+    provide_context(sheet.clone());
+    sheet
+}
+
+/// Returns the [`StyleSheet`] from context, if any.
+pub fn use_style_sheet() -> Option<StyleSheet> {
+    use_context::<StyleSheet>()
+}
+
+/// Returns the class name generated for the rules of a scoped style.
+pub(crate) fn class_name(css: &str) -> String {
+    format!("ls-{:016x}", hash(css))
+}
+
+/// Adds a rule to the [`StyleSheet`] from context if there is one (e.g. during SSR). Otherwise, it is injected
+/// into the document head on the client, at most once per class.
+pub(crate) fn register(class: &str, rule: String) {
+    if let Some(sheet) = use_style_sheet() {
+        sheet.insert(class, rule);
+    } else {
+        inject(class, &rule);
+    }
+}
+
+/// Returns the generated class name and rule for a style.
+pub fn scoped_rule<I: Into<Style>>(style: I) -> (String, String) {
+    let declarations = style.into().prepare_for_rule().to_string();
+    let class = class_name(&declarations);
+    let rule = format!(".{class} {{ {declarations} }}");
+
+    (class, rule)
+}
+
+/// Returns a generated class name for a style, registering its rule.
+///
+/// The rule is added to the [`StyleSheet`] from context if there is one (e.g. during SSR). Otherwise, it is injected
+/// into the document head on the client, at most once per class.
+pub fn scoped<I: Into<Style>>(style: I) -> String {
+    let (class, rule) = scoped_rule(style);
+    register(&class, rule);
+
+    class
+}
+
+#[cfg(target_arch = "wasm32")]
+fn inject(class: &str, rule: &str) {
+    use std::{cell::RefCell, collections::HashSet};
+
+    use leptos::{tachys::dom::document, web_sys::Element};
+
+    thread_local! {
+        static INJECTED: RefCell<(Option<Element>, HashSet<String>)> = Default::default();
+    }
+
+    INJECTED.with_borrow_mut(|(element, injected)| {
+        if !injected.insert(class.to_string()) {
+            return;
+        }
+
+        let document = document();
+        let element = element.get_or_insert_with(|| {
+            document
+                .query_selector(&format!("style[{STYLE_SHEET_ATTRIBUTE}]"))
+                .ok()
+                .flatten()
+                .unwrap_or_else(|| {
+                    let element = document
+                        .create_element("style")
+                        .expect("style element should be created");
+                    _ = element.set_attribute(STYLE_SHEET_ATTRIBUTE, "");
+                    if let Some(head) = document.head() {
+                        _ = head.append_child(&element);
+                    }
+                    element
+                })
+        });
+
+        // Rules rendered on the server are already present after hydration.
+        let text = element.text_content().unwrap_or_default();
+        if !text.contains(rule) {
+            element.set_text_content(Some(&format!("{text}\n{rule}")));
+        }
+    });
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn inject(_class: &str, _rule: &str) {}
+
+#[cfg(test)]
+mod tests {
+    use leptos::prelude::Owner;
+
+    use super::*;
+    use crate::sanitize::SanitizePolicy;
+
+    #[test]
+    fn test_scoped() {
+        let owner = Owner::new();
+        owner.set();
+
+        let sheet = provide_style_sheet();
+        assert_eq!("", sheet.to_html());
+
+        let class = scoped([("color", "red"), ("padding", "1rem")]);
+        assert!(class.starts_with("ls-"));
+        assert_eq!(class, scoped("color: red; padding: 1rem;"));
+        assert_ne!(class, scoped([("color", "blue")]));
+
+        assert_eq!(2, sheet.len());
+        assert_eq!(
+            format!(".{class} {{ color: red; padding: 1rem; }}"),
+            sheet.to_css().lines().next().unwrap(),
+        );
+        assert!(sheet.to_html().starts_with("<style data-leptos-style>.ls-"));
+
+        // Values that could end the rule are removed, even if inline styles are rendered as written.
+        provide_context(SanitizePolicy::Allow);
+        let (_, rule) = scoped_rule([("color", "red } body { display: none"), ("margin", "0")]);
+        assert!(rule.ends_with("{ margin: 0; }"));
+    }
+}
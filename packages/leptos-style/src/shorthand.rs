@@ -0,0 +1,831 @@
+use std::borrow::Cow;
+
+use indexmap::IndexMap;
+
+use crate::{parse::split_components, style::StyleMap, value::StyleValue};
+
+const SIDES: [&str; 4] = ["top", "right", "bottom", "left"];
+
+const CORNERS: [&str; 4] = ["top-left", "top-right", "bottom-right", "bottom-left"];
+
+const CSS_WIDE_KEYWORDS: [&str; 5] = ["inherit", "initial", "unset", "revert", "revert-layer"];
+
+const BORDER_STYLES: [&str; 10] = [
+    "none", "hidden", "dotted", "dashed", "solid", "double", "groove", "ridge", "inset", "outset",
+];
+
+const FONT_STRETCHES: [&str; 9] = [
+    "ultra-condensed",
+    "extra-condensed",
+    "condensed",
+    "semi-condensed",
+    "semi-expanded",
+    "expanded",
+    "extra-expanded",
+    "ultra-expanded",
+    "normal",
+];
+
+const SYSTEM_FONTS: [&str; 6] = [
+    "caption",
+    "icon",
+    "menu",
+    "message-box",
+    "small-caption",
+    "status-bar",
+];
+
+/// The longhands of a shorthand, in canonical order.
+pub(crate) fn longhands(shorthand: &str) -> Option<Vec<String>> {
+    let box_longhands = |format: fn(&str) -> String| Some(SIDES.map(format).to_vec());
+
+    match shorthand {
+        "margin" => box_longhands(|side| format!("margin-{side}")),
+        "padding" => box_longhands(|side| format!("padding-{side}")),
+        "inset" => box_longhands(|side| side.to_string()),
+        "border-width" => box_longhands(|side| format!("border-{side}-width")),
+        "border-style" => box_longhands(|side| format!("border-{side}-style")),
+        "border-color" => box_longhands(|side| format!("border-{side}-color")),
+        "border-radius" => Some(
+            CORNERS
+                .map(|corner| format!("border-{corner}-radius"))
+                .to_vec(),
+        ),
+        "border" => Some(
+            ["width", "style", "color"]
+                .into_iter()
+                .flat_map(|part| SIDES.map(|side| format!("border-{side}-{part}")))
+                .collect(),
+        ),
+        "border-top" | "border-right" | "border-bottom" | "border-left" => Some(
+            ["width", "style", "color"]
+                .map(|part| format!("{shorthand}-{part}"))
+                .to_vec(),
+        ),
+        "gap" => Some(vec!["row-gap".to_string(), "column-gap".to_string()]),
+        "overflow" => Some(vec!["overflow-x".to_string(), "overflow-y".to_string()]),
+        "flex" => Some(vec![
+            "flex-grow".to_string(),
+            "flex-shrink".to_string(),
+            "flex-basis".to_string(),
+        ]),
+        "font" => Some(
+            [
+                "font-style",
+                "font-variant",
+                "font-weight",
+                "font-stretch",
+                "font-size",
+                "line-height",
+                "font-family",
+            ]
+            .map(str::to_string)
+            .to_vec(),
+        ),
+        _ => None,
+    }
+}
+
+/// Expands 1-4 box values (`top right bottom left`) into four values.
+fn expand_box<'a>(values: &[&'a str]) -> Option<[&'a str; 4]> {
+    match *values {
+        [all] => Some([all; 4]),
+        [vertical, horizontal] => Some([vertical, horizontal, vertical, horizontal]),
+        [top, horizontal, bottom] => Some([top, horizontal, bottom, horizontal]),
+        [top, right, bottom, left] => Some([top, right, bottom, left]),
+        _ => None,
+    }
+}
+
+/// Collapses four box values (`top right bottom left`) into the shortest equivalent form.
+fn collapse_box(values: [&str; 4]) -> String {
+    match values {
+        [top, right, bottom, left] if right == left && top == bottom && top == right => {
+            top.to_string()
+        }
+        [top, right, bottom, left] if right == left && top == bottom => format!("{top} {right}"),
+        [top, right, bottom, left] if right == left => format!("{top} {right} {bottom}"),
+        [top, right, bottom, left] => format!("{top} {right} {bottom} {left}"),
+    }
+}
+
+fn is_number(value: &str) -> bool {
+    value.parse::<f64>().is_ok()
+}
+
+fn is_length(value: &str) -> bool {
+    value.starts_with(|char: char| char.is_ascii_digit() || matches!(char, '.' | '-' | '+'))
+        || ["calc(", "min(", "max(", "clamp("]
+            .iter()
+            .any(|function| value.starts_with(function))
+}
+
+fn expand_border_side(components: &[&str]) -> Option<[String; 3]> {
+    let mut width = None;
+    let mut style = None;
+    let mut color = None;
+
+    for component in components {
+        let slot = if BORDER_STYLES.contains(component) {
+            &mut style
+        } else if matches!(*component, "thin" | "medium" | "thick") || is_length(component) {
+            &mut width
+        } else {
+            &mut color
+        };
+
+        if slot.replace(component.to_string()).is_some() {
+            return None;
+        }
+    }
+
+    Some([
+        width.unwrap_or_else(|| "medium".to_string()),
+        style.unwrap_or_else(|| "none".to_string()),
+        color.unwrap_or_else(|| "currentcolor".to_string()),
+    ])
+}
+
+fn expand_border_radius(value: &str) -> Option<Vec<String>> {
+    let (horizontal, vertical) = match value.split_once('/') {
+        Some((horizontal, vertical)) => (horizontal, Some(vertical)),
+        None => (value, None),
+    };
+
+    let horizontal = expand_box(&split_components(horizontal))?;
+    let vertical = match vertical {
+        Some(vertical) => Some(expand_box(&split_components(vertical))?),
+        None => None,
+    };
+
+    Some(
+        (0..4)
+            .map(|index| match vertical {
+                Some(vertical) if vertical[index] != horizontal[index] => {
+                    format!("{} {}", horizontal[index], vertical[index])
+                }
+                _ => horizontal[index].to_string(),
+            })
+            .collect(),
+    )
+}
+
+fn expand_flex(components: &[&str]) -> Option<Vec<String>> {
+    let [grow, shrink, basis] = match *components {
+        ["none"] => ["0", "0", "auto"],
+        ["auto"] => ["1", "1", "auto"],
+        [grow] if is_number(grow) => [grow, "1", "0%"],
+        [basis] => ["1", "1", basis],
+        [grow, shrink] if is_number(grow) && is_number(shrink) => [grow, shrink, "0%"],
+        [grow, basis] if is_number(grow) => [grow, "1", basis],
+        [grow, shrink, basis] if is_number(grow) && is_number(shrink) => [grow, shrink, basis],
+        _ => return None,
+    };
+
+    Some(vec![
+        grow.to_string(),
+        shrink.to_string(),
+        basis.to_string(),
+    ])
+}
+
+fn expand_font(components: &[&str]) -> Option<Vec<String>> {
+    let mut style = None;
+    let mut variant = None;
+    let mut weight = None;
+    let mut stretch = None;
+
+    let mut components = components.iter().copied().peekable();
+    while let Some(component) = components.peek().copied() {
+        let slot = match component {
+            "normal" => None,
+            "italic" | "oblique" => Some(&mut style),
+            "small-caps" => Some(&mut variant),
+            "bold" | "bolder" | "lighter" => Some(&mut weight),
+            component if is_number(component) => Some(&mut weight),
+            component if FONT_STRETCHES.contains(&component) => Some(&mut stretch),
+            _ => break,
+        };
+
+        if slot.is_some_and(|slot| slot.replace(component.to_string()).is_some()) {
+            return None;
+        }
+        components.next();
+    }
+
+    let size = components.next()?;
+    let (size, line_height) = match size.split_once('/') {
+        Some((size, "")) => (size, components.next()?),
+        Some((size, line_height)) => (size, line_height),
+        None => match components.next_if(|component| component.starts_with('/')) {
+            Some("/") => (size, components.next()?),
+            Some(line_height) => (size, &line_height[1..]),
+            None => (size, "normal"),
+        },
+    };
+
+    let family = components.collect::<Vec<_>>().join(" ");
+    if family.is_empty() {
+        return None;
+    }
+
+    let normal = || "normal".to_string();
+
+    Some(vec![
+        style.unwrap_or_else(normal),
+        variant.unwrap_or_else(normal),
+        weight.unwrap_or_else(normal),
+        stretch.unwrap_or_else(normal),
+        size.to_string(),
+        line_height.to_string(),
+        family,
+    ])
+}
+
+/// Expands a shorthand into `(longhand, value)` pairs.
+///
+/// Returns `None` if `property` is not a supported shorthand or the value cannot be expanded
+/// without resolving it (e.g. it contains `var()`).
+pub(crate) fn expand_shorthand(property: &str, value: &str) -> Option<Vec<(String, String)>> {
+    let longhands = longhands(property)?;
+
+    if value.contains("var(") || value.contains("env(") || value.contains("attr(") {
+        return None;
+    }
+
+    if CSS_WIDE_KEYWORDS.contains(&value) {
+        return Some(
+            longhands
+                .into_iter()
+                .map(|longhand| (longhand, value.to_string()))
+                .collect(),
+        );
+    }
+
+    let components = split_components(value);
+
+    let values: Vec<String> = match property {
+        "margin" | "padding" | "inset" | "border-width" | "border-style" | "border-color" => {
+            expand_box(&components)?.map(str::to_string).to_vec()
+        }
+        "border-radius" => expand_border_radius(value)?,
+        "border" => {
+            let [width, style, color] = expand_border_side(&components)?;
+            [width, style, color]
+                .into_iter()
+                .flat_map(|value| [value.clone(), value.clone(), value.clone(), value])
+                .collect()
+        }
+        "border-top" | "border-right" | "border-bottom" | "border-left" => {
+            expand_border_side(&components)?.to_vec()
+        }
+        "gap" | "overflow" => match *components {
+            [all] => vec![all.to_string(), all.to_string()],
+            [first, second] => vec![first.to_string(), second.to_string()],
+            _ => return None,
+        },
+        "flex" => expand_flex(&components)?,
+        "font" => {
+            if components.len() == 1 && SYSTEM_FONTS.contains(&components[0]) {
+                return None;
+            }
+            expand_font(&components)?
+        }
+        _ => return None,
+    };
+
+    Some(longhands.into_iter().zip(values).collect())
+}
+
+/// Replaces shorthands with their longhands, in place.
+pub(crate) fn expand_shorthands(map: StyleMap) -> StyleMap {
+    let mut expanded = IndexMap::with_capacity(map.len());
+
+    for (key, value) in map {
+        let longhands = match &value {
+            Some(value) => expand_shorthand(&key, value).map(|longhands| {
+                longhands
+                    .into_iter()
+                    .map(|(longhand, longhand_value)| {
+                        (
+                            Cow::Owned(longhand),
+                            Some(StyleValue {
+                                value: longhand_value.into(),
+                                important: value.important,
+                            }),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            }),
+            None => longhands(&key).map(|longhands| {
+                longhands
+                    .into_iter()
+                    .map(|longhand| (Cow::Owned(longhand), None))
+                    .collect()
+            }),
+        };
+
+        match longhands {
+            Some(longhands) => {
+                for (longhand, value) in longhands {
+                    // Expand nested shorthands (e.g. `border` into `border-top-width`).
+                    expanded.shift_remove(&longhand);
+                    expanded.insert(longhand, value);
+                }
+            }
+            None => {
+                expanded.shift_remove(&key);
+                expanded.insert(key, value);
+            }
+        }
+    }
+
+    expanded
+}
+
+fn collapse_values(shorthand: &str, values: &[&str]) -> Option<String> {
+    match shorthand {
+        "margin" | "padding" | "inset" | "border-width" | "border-style" | "border-color" => {
+            Some(collapse_box(values.try_into().ok()?))
+        }
+        "border-radius" => {
+            let corners = values
+                .iter()
+                .map(|value| match *split_components(value) {
+                    [horizontal] => Some((horizontal, horizontal)),
+                    [horizontal, vertical] => Some((horizontal, vertical)),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()?;
+            let horizontal = collapse_box(
+                corners
+                    .iter()
+                    .map(|(h, _)| *h)
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .ok()?,
+            );
+            let vertical = collapse_box(
+                corners
+                    .iter()
+                    .map(|(_, v)| *v)
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .ok()?,
+            );
+
+            Some(if horizontal == vertical {
+                horizontal
+            } else {
+                format!("{horizontal} / {vertical}")
+            })
+        }
+        "border" => {
+            let [width, style, color] = [0, 4, 8].map(|start| &values[start..start + 4]);
+            (width.iter().all(|value| *value == width[0])
+                && style.iter().all(|value| *value == style[0])
+                && color.iter().all(|value| *value == color[0]))
+            .then(|| format!("{} {} {}", width[0], style[0], color[0]))
+        }
+        "gap" | "overflow" => Some(if values[0] == values[1] {
+            values[0].to_string()
+        } else {
+            format!("{} {}", values[0], values[1])
+        }),
+        "flex" => Some(values.join(" ")),
+        "font" => {
+            let [style, variant, weight, stretch, size, line_height, family] =
+                values.try_into().ok()?;
+
+            // Only CSS 2.1 font variants and keyword stretches are allowed in the shorthand.
+            if !matches!(variant, "normal" | "small-caps") || !FONT_STRETCHES.contains(&stretch) {
+                return None;
+            }
+
+            let size = if line_height == "normal" {
+                size.to_string()
+            } else {
+                format!("{size}/{line_height}")
+            };
+
+            Some(
+                [style, variant, weight, stretch]
+                    .into_iter()
+                    .filter(|value| *value != "normal")
+                    .chain([size.as_str(), family])
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            )
+        }
+        _ => None,
+    }
+}
+
+/// Replaces complete sets of longhands with their shorthand, at the position of the first longhand.
+///
+/// Longhands are only collapsed if they are all declared with the same priority.
+pub(crate) fn collapse_shorthands(mut map: StyleMap) -> StyleMap {
+    for shorthand in [
+        "border",
+        "border-width",
+        "border-style",
+        "border-color",
+        "border-radius",
+        "margin",
+        "padding",
+        "inset",
+        "gap",
+        "overflow",
+        "flex",
+        "font",
+    ] {
+        let longhands = longhands(shorthand).expect("Shorthand should have longhands.");
+
+        let Some(values) = longhands
+            .iter()
+            .map(|longhand| map.get(longhand.as_str()).and_then(|value| value.as_ref()))
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+
+        let important = values[0].important;
+        if values.iter().any(|value| value.important != important) {
+            continue;
+        }
+
+        let Some(value) = collapse_values(
+            shorthand,
+            &values
+                .iter()
+                .map(|value| value.value.as_ref())
+                .collect::<Vec<_>>(),
+        ) else {
+            continue;
+        };
+
+        let index = longhands
+            .iter()
+            .filter_map(|longhand| map.get_index_of(longhand.as_str()))
+            .min()
+            .expect("Longhands should be in map.");
+
+        map.shift_insert(
+            index,
+            Cow::Borrowed(shorthand),
+            Some(StyleValue {
+                value: value.into(),
+                important,
+            }),
+        );
+        for longhand in &longhands {
+            map.shift_remove(longhand.as_str());
+        }
+    }
+
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Declarations = &'static [(&'static str, &'static str)];
+
+    #[test]
+    fn test_expand_shorthand() {
+        let cases: [(&str, &str, Declarations); 24] = [
+            ("color", "red", &[]),
+            ("margin", "var(--spacing)", &[]),
+            (
+                "margin",
+                "1px",
+                &[
+                    ("margin-top", "1px"),
+                    ("margin-right", "1px"),
+                    ("margin-bottom", "1px"),
+                    ("margin-left", "1px"),
+                ],
+            ),
+            (
+                "margin",
+                "1px 2px",
+                &[
+                    ("margin-top", "1px"),
+                    ("margin-right", "2px"),
+                    ("margin-bottom", "1px"),
+                    ("margin-left", "2px"),
+                ],
+            ),
+            (
+                "padding",
+                "1px 2px 3px",
+                &[
+                    ("padding-top", "1px"),
+                    ("padding-right", "2px"),
+                    ("padding-bottom", "3px"),
+                    ("padding-left", "2px"),
+                ],
+            ),
+            (
+                "padding",
+                "1px calc(2px + 1em) 3px 4px",
+                &[
+                    ("padding-top", "1px"),
+                    ("padding-right", "calc(2px + 1em)"),
+                    ("padding-bottom", "3px"),
+                    ("padding-left", "4px"),
+                ],
+            ),
+            (
+                "inset",
+                "0",
+                &[("top", "0"), ("right", "0"), ("bottom", "0"), ("left", "0")],
+            ),
+            ("inset", "1px 2px 3px 4px 5px", &[]),
+            (
+                "margin",
+                "inherit",
+                &[
+                    ("margin-top", "inherit"),
+                    ("margin-right", "inherit"),
+                    ("margin-bottom", "inherit"),
+                    ("margin-left", "inherit"),
+                ],
+            ),
+            (
+                "border-width",
+                "1px 2px",
+                &[
+                    ("border-top-width", "1px"),
+                    ("border-right-width", "2px"),
+                    ("border-bottom-width", "1px"),
+                    ("border-left-width", "2px"),
+                ],
+            ),
+            (
+                "border",
+                "1px solid red",
+                &[
+                    ("border-top-width", "1px"),
+                    ("border-right-width", "1px"),
+                    ("border-bottom-width", "1px"),
+                    ("border-left-width", "1px"),
+                    ("border-top-style", "solid"),
+                    ("border-right-style", "solid"),
+                    ("border-bottom-style", "solid"),
+                    ("border-left-style", "solid"),
+                    ("border-top-color", "red"),
+                    ("border-right-color", "red"),
+                    ("border-bottom-color", "red"),
+                    ("border-left-color", "red"),
+                ],
+            ),
+            (
+                "border-top",
+                "dashed",
+                &[
+                    ("border-top-width", "medium"),
+                    ("border-top-style", "dashed"),
+                    ("border-top-color", "currentcolor"),
+                ],
+            ),
+            (
+                "border-left",
+                "thick rgb(0 0 0) double",
+                &[
+                    ("border-left-width", "thick"),
+                    ("border-left-style", "double"),
+                    ("border-left-color", "rgb(0 0 0)"),
+                ],
+            ),
+            ("border-left", "solid solid", &[]),
+            (
+                "border-radius",
+                "4px 8px",
+                &[
+                    ("border-top-left-radius", "4px"),
+                    ("border-top-right-radius", "8px"),
+                    ("border-bottom-right-radius", "4px"),
+                    ("border-bottom-left-radius", "8px"),
+                ],
+            ),
+            (
+                "border-radius",
+                "4px / 2px",
+                &[
+                    ("border-top-left-radius", "4px 2px"),
+                    ("border-top-right-radius", "4px 2px"),
+                    ("border-bottom-right-radius", "4px 2px"),
+                    ("border-bottom-left-radius", "4px 2px"),
+                ],
+            ),
+            (
+                "gap",
+                "1rem",
+                &[("row-gap", "1rem"), ("column-gap", "1rem")],
+            ),
+            (
+                "gap",
+                "1rem 2rem",
+                &[("row-gap", "1rem"), ("column-gap", "2rem")],
+            ),
+            (
+                "overflow",
+                "hidden auto",
+                &[("overflow-x", "hidden"), ("overflow-y", "auto")],
+            ),
+            (
+                "flex",
+                "none",
+                &[
+                    ("flex-grow", "0"),
+                    ("flex-shrink", "0"),
+                    ("flex-basis", "auto"),
+                ],
+            ),
+            (
+                "flex",
+                "2",
+                &[
+                    ("flex-grow", "2"),
+                    ("flex-shrink", "1"),
+                    ("flex-basis", "0%"),
+                ],
+            ),
+            (
+                "flex",
+                "1 30px",
+                &[
+                    ("flex-grow", "1"),
+                    ("flex-shrink", "1"),
+                    ("flex-basis", "30px"),
+                ],
+            ),
+            (
+                "font",
+                "italic bold 12px/30px Georgia, serif",
+                &[
+                    ("font-style", "italic"),
+                    ("font-variant", "normal"),
+                    ("font-weight", "bold"),
+                    ("font-stretch", "normal"),
+                    ("font-size", "12px"),
+                    ("line-height", "30px"),
+                    ("font-family", "Georgia, serif"),
+                ],
+            ),
+            (
+                "font",
+                "600 1.2em / 1.5 \"Open Sans\"",
+                &[
+                    ("font-style", "normal"),
+                    ("font-variant", "normal"),
+                    ("font-weight", "600"),
+                    ("font-stretch", "normal"),
+                    ("font-size", "1.2em"),
+                    ("line-height", "1.5"),
+                    ("font-family", "\"Open Sans\""),
+                ],
+            ),
+        ];
+
+        for (property, value, expected) in cases {
+            assert_eq!(
+                (!expected.is_empty()).then(|| expected
+                    .iter()
+                    .map(|(longhand, value)| (longhand.to_string(), value.to_string()))
+                    .collect::<Vec<_>>()),
+                expand_shorthand(property, value),
+                "{property}: {value}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_collapse_shorthands() {
+        let cases: [(Declarations, Declarations); 10] = [
+            (
+                &[
+                    ("margin-top", "1px"),
+                    ("margin-right", "1px"),
+                    ("margin-bottom", "1px"),
+                    ("margin-left", "1px"),
+                ],
+                &[("margin", "1px")],
+            ),
+            (
+                &[
+                    ("margin-top", "1px"),
+                    ("margin-right", "2px"),
+                    ("margin-bottom", "1px"),
+                    ("margin-left", "2px"),
+                ],
+                &[("margin", "1px 2px")],
+            ),
+            (
+                &[
+                    ("margin-top", "1px"),
+                    ("margin-right", "2px"),
+                    ("margin-bottom", "3px"),
+                    ("margin-left", "2px"),
+                ],
+                &[("margin", "1px 2px 3px")],
+            ),
+            (
+                &[
+                    ("margin-top", "1px"),
+                    ("margin-right", "2px"),
+                    ("margin-bottom", "3px"),
+                    ("margin-left", "4px"),
+                ],
+                &[("margin", "1px 2px 3px 4px")],
+            ),
+            (
+                &[
+                    ("color", "red"),
+                    ("padding-top", "1px"),
+                    ("padding-left", "1px"),
+                ],
+                &[
+                    ("color", "red"),
+                    ("padding-top", "1px"),
+                    ("padding-left", "1px"),
+                ],
+            ),
+            (
+                &[
+                    ("color", "red"),
+                    ("row-gap", "1rem"),
+                    ("width", "1px"),
+                    ("column-gap", "1rem"),
+                ],
+                &[("color", "red"), ("gap", "1rem"), ("width", "1px")],
+            ),
+            (
+                &[("overflow-x", "hidden"), ("overflow-y", "auto")],
+                &[("overflow", "hidden auto")],
+            ),
+            (
+                &[
+                    ("flex-grow", "1"),
+                    ("flex-shrink", "1"),
+                    ("flex-basis", "0%"),
+                ],
+                &[("flex", "1 1 0%")],
+            ),
+            (
+                &[
+                    ("border-top-left-radius", "4px 2px"),
+                    ("border-top-right-radius", "4px"),
+                    ("border-bottom-right-radius", "4px 2px"),
+                    ("border-bottom-left-radius", "4px"),
+                ],
+                &[("border-radius", "4px / 2px 4px")],
+            ),
+            (
+                &[
+                    ("font-style", "italic"),
+                    ("font-variant", "normal"),
+                    ("font-weight", "bold"),
+                    ("font-stretch", "normal"),
+                    ("font-size", "12px"),
+                    ("line-height", "30px"),
+                    ("font-family", "Georgia, serif"),
+                ],
+                &[("font", "italic bold 12px/30px Georgia, serif")],
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let map = |declarations: &[(&'static str, &'static str)]| {
+                declarations
+                    .iter()
+                    .map(|(key, value)| (Cow::Borrowed(*key), Some(StyleValue::from(*value))))
+                    .collect::<StyleMap>()
+            };
+
+            assert_eq!(map(expected), collapse_shorthands(map(input)), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for (property, value) in [
+            ("margin", "1px 2px 3px"),
+            ("border", "1px solid red"),
+            ("border-radius", "4px 8px"),
+            ("gap", "1rem 2rem"),
+            ("flex", "2 1 30px"),
+            ("font", "italic bold 12px/30px Georgia, serif"),
+        ] {
+            let map = StyleMap::from([(Cow::Borrowed(property), Some(StyleValue::from(value)))]);
+
+            assert_eq!(
+                map,
+                collapse_shorthands(expand_shorthands(map.clone())),
+                "{property}: {value}"
+            );
+        }
+    }
+}
@@ -1,26 +1,141 @@
 use std::{
-    fmt::{self, Display},
+    borrow::Cow,
+    fmt::{self, Display, Write},
+    mem,
     ops::Deref,
 };
 
 use indexmap::IndexMap;
-use leptos::tachys::html::style::IntoStyle;
+use leptos::{
+    logging::error,
+    prelude::{
+        untrack, use_context, Get, GetUntracked, Memo, ReadSignal, RwSignal, Signal, Track, With,
+    },
+    reactive::effect::RenderEffect,
+    tachys::{
+        html::style::IntoStyle,
+        renderer::{types::Element, Rndr},
+    },
+};
 
-fn style_map_to_string(map: &IndexMap<String, Option<String>>) -> String {
-    map.iter()
-        .filter_map(|(key, value)| {
-            value
-                .as_ref()
-                .and_then(|value| (!value.is_empty()).then_some(format!("{key}: {value};")))
-        })
-        .collect::<Vec<_>>()
-        .join(" ")
+use crate::{
+    conditional::{ConditionalRender, ConditionalStyle},
+    custom_property::{custom_property_name, is_custom_property},
+    format::{canonical_map, StyleFormat},
+    merge::{merge_style_maps, MergeStrategy},
+    parse::{is_declaration_list, parse_declarations},
+    prefix::Prefixer,
+    property::{normalize_property, number_value},
+    sanitize::{sanitize_declaration, SanitizeError, SanitizePolicy},
+    shorthand::{collapse_shorthands, expand_shorthands, longhands},
+    value::StyleValue,
+};
+
+/// Structured declarations, keyed by property.
+pub type StyleMap = IndexMap<Cow<'static, str>, Option<StyleValue>>;
+
+/// Writes declarations separated by spaces, skipping unset and empty values.
+fn write_style_map<W: Write>(map: &StyleMap, f: &mut W) -> fmt::Result {
+    let mut first = true;
+    for (key, value) in map {
+        if let Some(value) = value.as_ref().filter(|value| !value.is_empty()) {
+            if !first {
+                f.write_char(' ')?;
+            }
+            first = false;
+
+            write!(f, "{key}: {value};")?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the new text of a sanitized or normalized property or value, or `None` if it was kept as is.
+fn changed(text: Cow<'_, str>) -> Option<String> {
+    match text {
+        Cow::Borrowed(_) => None,
+        Cow::Owned(text) => Some(text),
+    }
+}
+
+/// Converts a property name to kebab-case, only allocating if an owned name is not already kebab-case.
+fn normalize_key<K: Into<Cow<'static, str>>>(key: K) -> Cow<'static, str> {
+    match key.into() {
+        Cow::Borrowed(key) => normalize_property(key),
+        Cow::Owned(key) => Cow::Owned(changed(normalize_property(&key)).unwrap_or(key)),
+    }
 }
 
+/// Converts a borrowed property name to an owned kebab-case key.
+fn owned_key(key: &str) -> Cow<'static, str> {
+    Cow::Owned(normalize_property(key).into_owned())
+}
+
+/// Returns the value of `key` if it is set to a non-empty value.
+fn declared_value<'a>(map: &'a StyleMap, key: &str) -> Option<&'a StyleValue> {
+    map.get(key)
+        .and_then(|value| value.as_ref())
+        .filter(|value| !value.is_empty())
+}
+
+/// Parses a string style into a structured map. Later declarations of a property replace earlier ones.
+fn parse_style_map(string: &str) -> StyleMap {
+    parse_declarations(string)
+        .into_iter()
+        .map(|(key, value)| (Cow::Owned(key), Some(StyleValue::from(value))))
+        .collect()
+}
+
+/// Moves custom property definitions before all other declarations, keeping their relative order.
+pub(crate) fn hoist_custom_properties(map: StyleMap) -> StyleMap {
+    let (custom_properties, declarations): (IndexMap<_, _>, IndexMap<_, _>) = map
+        .into_iter()
+        .partition(|(key, _)| is_custom_property(key));
+
+    custom_properties.into_iter().chain(declarations).collect()
+}
+
+/// With the `serde` feature, string styles are serialized as a string and structured styles as an ordered map.
+/// Reactive and conditional styles are serialized as their current value.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
 pub enum InnerStyle {
     String(String),
-    Structured(IndexMap<String, Option<String>>),
+    Structured(StyleMap),
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_reactive", skip_deserializing)
+    )]
+    Reactive(Signal<Style>),
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_conditional", skip_deserializing)
+    )]
+    Conditional(ConditionalStyle),
+}
+
+#[cfg(feature = "serde")]
+fn serialize_reactive<S: serde::Serializer>(
+    signal: &Signal<Style>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    use serde::Serialize;
+
+    signal.with(|style| style.serialize(serializer))
+}
+
+#[cfg(feature = "serde")]
+fn serialize_conditional<S: serde::Serializer>(
+    conditional: &ConditionalStyle,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    use serde::Serialize;
+
+    conditional.current().serialize(serializer)
 }
 
 impl InnerStyle {
@@ -28,18 +143,157 @@ impl InnerStyle {
         let defaults: InnerStyle = defaults.into();
 
         match (self, defaults) {
+            // Defaults only apply to the base style, entries override them.
+            (
+                Self::Conditional(mut conditional),
+                defaults @ (Self::String(_) | Self::Structured(_)),
+            ) => {
+                conditional.base = Box::new(conditional.base.with_defaults(Style(Some(defaults))));
+                Self::Conditional(conditional)
+            }
+            (style @ (Self::Reactive(_) | Self::Conditional(_)), defaults)
+            | (style, defaults @ (Self::Reactive(_) | Self::Conditional(_))) => {
+                let (style, defaults) = (Style(Some(style)), Style(Some(defaults)));
+
+                Self::Reactive(Signal::derive(move || {
+                    style.current().with_defaults(defaults.current())
+                }))
+            }
             (Self::String(string), Self::String(default_string)) => {
                 Self::String(format!("{default_string} {string}"))
             }
             (Self::String(string), Self::Structured(default_map)) => {
-                Self::String(format!("{} {}", style_map_to_string(&default_map), string))
+                Self::String(format!("{} {}", Self::Structured(default_map), string))
             }
             (Self::Structured(map), Self::String(default_string)) => {
-                Self::String(format!("{} {}", default_string, style_map_to_string(&map)))
+                Self::String(format!("{} {}", default_string, Self::Structured(map)))
             }
             (Self::Structured(map), Self::Structured(default_map)) => {
-                InnerStyle::Structured(default_map.into_iter().chain(map).collect())
+                InnerStyle::Structured(hoist_custom_properties(merge_style_maps(
+                    default_map,
+                    map,
+                    MergeStrategy::Override,
+                )))
+            }
+        }
+    }
+
+    /// Converts to a structured map, parsing string styles. Reactive styles are converted to their current value.
+    pub(crate) fn into_structured(self) -> StyleMap {
+        match self {
+            Self::String(string) => parse_style_map(&string),
+            Self::Structured(map) => map,
+            Self::Reactive(signal) => signal
+                .get_untracked()
+                .0
+                .map(InnerStyle::into_structured)
+                .unwrap_or_default(),
+            Self::Conditional(conditional) => untrack(|| conditional.current())
+                .0
+                .map(InnerStyle::into_structured)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Sanitizes property names and values according to `policy`.
+    ///
+    /// String styles are parsed and re-rendered, so text outside of declarations is removed. Reactive styles are
+    /// sanitized lazily, a rejected value is logged and rendered as an empty style.
+    pub fn sanitize(self, policy: SanitizePolicy) -> Result<Self, SanitizeError> {
+        if policy == SanitizePolicy::Allow {
+            return Ok(self);
+        }
+
+        Ok(match self {
+            Self::Reactive(signal) => Self::Reactive(Signal::derive(move || {
+                signal.get().into_current().sanitize_or_default(policy)
+            })),
+            Self::Conditional(conditional) => Self::Reactive(Signal::derive(move || {
+                conditional.current().sanitize_or_default(policy)
+            })),
+            Self::String(string) => {
+                // A string of valid declarations is kept as written, preserving its format.
+                let mut unchanged = is_declaration_list(&string);
+                let mut sanitized = String::with_capacity(string.len());
+                for (key, value) in parse_declarations(&string) {
+                    match sanitize_declaration(&key, &value, policy)? {
+                        Some((key, value)) => {
+                            unchanged &=
+                                matches!((&key, &value), (Cow::Borrowed(_), Cow::Borrowed(_)));
+                            if !sanitized.is_empty() {
+                                sanitized.push(' ');
+                            }
+                            _ = write!(sanitized, "{key}: {value};");
+                        }
+                        None => unchanged = false,
+                    }
+                }
+                Self::String(if unchanged { string } else { sanitized })
+            }
+            Self::Structured(map) => {
+                // Only changed declarations are collected, so valid maps are kept as is without allocating.
+                let mut edits = vec![];
+                for (index, (key, value)) in map.iter().enumerate() {
+                    let Some(value) = value else {
+                        continue;
+                    };
+                    match sanitize_declaration(key, value, policy)? {
+                        Some((key, value)) => match (changed(key), changed(value)) {
+                            (None, None) => {}
+                            edit => edits.push((index, Some(edit))),
+                        },
+                        None => edits.push((index, None)),
+                    }
+                }
+                if edits.is_empty() {
+                    return Ok(Self::Structured(map));
+                }
+
+                let mut edits = edits.into_iter().peekable();
+                let mut sanitized = StyleMap::with_capacity(map.len());
+                for (index, (key, value)) in map.into_iter().enumerate() {
+                    let Some(edit) = edits.next_if(|(edit_index, _)| *edit_index == index) else {
+                        sanitized.insert(key, value);
+                        continue;
+                    };
+                    let (Some((escaped_key, escaped_value)), Some(value)) = (edit.1, value) else {
+                        continue;
+                    };
+
+                    sanitized.insert(
+                        escaped_key.map_or(key, Cow::Owned),
+                        Some(StyleValue {
+                            important: value.important,
+                            value: escaped_value.map_or(value.value, Cow::Owned),
+                        }),
+                    );
+                }
+                Self::Structured(sanitized)
             }
+        })
+    }
+
+    /// Returns the custom properties defined in this style, in declaration order.
+    ///
+    /// For string styles, later definitions of the same property take precedence.
+    pub fn custom_properties(&self) -> IndexMap<String, String> {
+        match self {
+            Self::String(string) => parse_declarations(string)
+                .into_iter()
+                .filter(|(key, _)| is_custom_property(key))
+                .map(|(key, value)| (key, StyleValue::from(value).value.into_owned()))
+                .collect(),
+            Self::Structured(map) => map
+                .iter()
+                .filter(|(key, _)| is_custom_property(key))
+                .filter_map(|(key, value)| {
+                    value
+                        .as_ref()
+                        .map(|value| (key.to_string(), value.value.to_string()))
+                })
+                .collect(),
+            Self::Reactive(signal) => signal.with(Style::custom_properties),
+            Self::Conditional(conditional) => conditional.current().custom_properties(),
         }
     }
 }
@@ -47,20 +301,134 @@ impl InnerStyle {
 impl Display for InnerStyle {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::String(string) => write!(f, "{}", string),
-            Self::Structured(map) => write!(f, "{}", style_map_to_string(map),),
+            Self::String(string) => f.write_str(string),
+            Self::Structured(map) => write_style_map(map, f),
+            Self::Reactive(signal) => signal.with(|style| style.fmt(f)),
+            Self::Conditional(conditional) => conditional.current().fmt(f),
         }
     }
 }
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Style(pub Option<InnerStyle>);
 
+/// Styles are equal if their canonical declarations are equal in the same order, see [`Style::canonicalize`].
+/// Reactive styles are only equal to styles reading the same signal.
+impl PartialEq for Style {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Some(InnerStyle::Reactive(signal)), Some(InnerStyle::Reactive(other))) => {
+                signal == other
+            }
+            (Some(InnerStyle::Conditional(conditional)), Some(InnerStyle::Conditional(other))) => {
+                conditional == other
+            }
+            (Some(InnerStyle::Reactive(_) | InnerStyle::Conditional(_)), _)
+            | (_, Some(InnerStyle::Reactive(_) | InnerStyle::Conditional(_))) => false,
+            _ if self.same_declarations(other) => true,
+            // Declaration order matters, e.g. `margin: 0; margin-top: 4px;` differs from the reverse.
+            _ => canonical_map(self.current_map())
+                .iter()
+                .eq(canonical_map(other.current_map()).iter()),
+        }
+    }
+}
+
 impl Style {
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Creates a structured style from static declarations, borrowing the keys and values instead of copying them.
+    ///
+    /// `Style::from([(&str, &str); N])` accepts strings of any lifetime, so it always copies them.
+    pub fn from_static<const N: usize>(declarations: [(&'static str, &'static str); N]) -> Self {
+        Style(Some(InnerStyle::Structured(StyleMap::from_iter(
+            declarations.map(|(key, value)| {
+                (
+                    normalize_key(key),
+                    Some(StyleValue::from(Cow::Borrowed(value))),
+                )
+            }),
+        ))))
+    }
+
+    /// Creates a reactive style, which is updated when signals read by `f` change.
+    ///
+    /// Methods returning a new style (e.g. [`Style::with_defaults`]) are applied to each value lazily. Mutating
+    /// methods (e.g. [`Style::set`]) replace a reactive style with its current value.
+    pub fn reactive<F: Fn() -> S + Send + Sync + 'static, S: Into<Style>>(f: F) -> Self {
+        Style(Some(InnerStyle::Reactive(Signal::derive(move || {
+            f().into()
+        }))))
+    }
+
+    /// Returns whether this is a reactive or conditional style.
+    pub fn is_reactive(&self) -> bool {
+        matches!(
+            self.0,
+            Some(InnerStyle::Reactive(_) | InnerStyle::Conditional(_))
+        )
+    }
+
+    /// Returns the current value of a reactive style, tracking it in a reactive context. Static styles are cloned.
+    pub fn current(&self) -> Style {
+        match &self.0 {
+            Some(InnerStyle::Reactive(signal)) => signal.with(Style::current),
+            Some(InnerStyle::Conditional(conditional)) => conditional.current(),
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns the declarations as a structured map, reading a reactive style untracked.
+    pub(crate) fn current_map(&self) -> StyleMap {
+        self.0
+            .clone()
+            .map(InnerStyle::into_structured)
+            .unwrap_or_default()
+    }
+
+    /// Returns whether both styles are static and have the same declarations as written, in the same order.
+    ///
+    /// Unlike [`PartialEq`], declarations are not parsed or canonicalized, so this is cheap enough to run on every
+    /// render.
+    pub(crate) fn same_declarations(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (None, None) => true,
+            (Some(InnerStyle::String(string)), Some(InnerStyle::String(other))) => string == other,
+            (Some(InnerStyle::Structured(map)), Some(InnerStyle::Structured(other))) => {
+                map.iter().eq(other.iter())
+            }
+            _ => false,
+        }
+    }
+
+    fn into_current(self) -> Style {
+        match self.0 {
+            Some(InnerStyle::Reactive(signal)) => signal.get().into_current(),
+            Some(InnerStyle::Conditional(conditional)) => conditional.current(),
+            _ => self,
+        }
+    }
+
+    /// Applies `f` to a static style, or lazily to each value of a reactive style.
+    pub(crate) fn map_static<F: Fn(Style) -> Style + Send + Sync + 'static>(self, f: F) -> Self {
+        match self.0 {
+            Some(InnerStyle::Reactive(signal)) => {
+                Style::reactive(move || f(signal.get().into_current()))
+            }
+            Some(InnerStyle::Conditional(conditional)) => {
+                Style::reactive(move || f(conditional.current()))
+            }
+            _ => f(self),
+        }
+    }
+
     pub fn with_defaults<I: Into<Self>>(self, defaults: I) -> Self {
         let defaults: Self = defaults.into();
 
@@ -71,6 +439,391 @@ impl Style {
             (None, None) => None,
         })
     }
+
+    /// Merges another style into this style using `strategy`.
+    ///
+    /// For [`MergeStrategy::Override`] and [`MergeStrategy::Preserve`], string styles are concatenated like in
+    /// [`Style::with_defaults`]. Other strategies convert string styles to structured styles.
+    pub fn merge<I: Into<Self>>(self, other: I, strategy: MergeStrategy) -> Self {
+        let other: Self = other.into();
+
+        Style(match (self.0, other.0) {
+            (Some(style), Some(other))
+                if matches!(style, InnerStyle::Reactive(_) | InnerStyle::Conditional(_))
+                    || matches!(other, InnerStyle::Reactive(_) | InnerStyle::Conditional(_)) =>
+            {
+                let (style, other) = (Style(Some(style)), Style(Some(other)));
+
+                Some(InnerStyle::Reactive(Signal::derive(move || {
+                    style.current().merge(other.current(), strategy)
+                })))
+            }
+            (Some(InnerStyle::Structured(map)), Some(InnerStyle::Structured(other_map))) => {
+                Some(InnerStyle::Structured(hoist_custom_properties(
+                    merge_style_maps(map, other_map, strategy),
+                )))
+            }
+            (Some(style), Some(other)) => Some(match strategy {
+                MergeStrategy::Override => other.with_defaults(style),
+                MergeStrategy::Preserve => style.with_defaults(other),
+                MergeStrategy::Remove | MergeStrategy::Combine => {
+                    InnerStyle::Structured(hoist_custom_properties(merge_style_maps(
+                        style.into_structured(),
+                        other.into_structured(),
+                        strategy,
+                    )))
+                }
+            }),
+            (Some(style), None) => Some(style),
+            (None, Some(other)) => (strategy != MergeStrategy::Remove).then_some(other),
+            (None, None) => None,
+        })
+    }
+
+    /// Like [`Style::with_defaults`], but shorthands are expanded before merging,
+    /// so e.g. a `margin` overrides a default `margin-top`.
+    ///
+    /// The result contains longhands, use [`Style::collapse_shorthands`] to convert them back.
+    pub fn with_defaults_expanded<I: Into<Self>>(self, defaults: I) -> Self {
+        self.expand_shorthands()
+            .with_defaults(defaults.into().expand_shorthands())
+    }
+
+    /// Replaces shorthands (e.g. `margin`) with their longhands (e.g. `margin-top`).
+    ///
+    /// String styles are converted to structured styles. Shorthands with values that cannot be
+    /// expanded without resolving them (e.g. `var()` references) are kept.
+    pub fn expand_shorthands(self) -> Self {
+        self.map_static(|style| {
+            Style(
+                style.0.map(|style| {
+                    InnerStyle::Structured(expand_shorthands(style.into_structured()))
+                }),
+            )
+        })
+    }
+
+    /// Replaces complete sets of longhands (e.g. `margin-top`) with their shorthand (e.g. `margin`).
+    ///
+    /// String styles are converted to structured styles.
+    pub fn collapse_shorthands(self) -> Self {
+        self.map_static(|style| {
+            Style(
+                style.0.map(|style| {
+                    InnerStyle::Structured(collapse_shorthands(style.into_structured()))
+                }),
+            )
+        })
+    }
+
+    /// Sanitizes property names and values according to `policy`.
+    pub fn sanitize(self, policy: SanitizePolicy) -> Result<Self, SanitizeError> {
+        if policy == SanitizePolicy::Allow {
+            return Ok(self);
+        }
+
+        Ok(Style(
+            self.0.map(|style| style.sanitize(policy)).transpose()?,
+        ))
+    }
+
+    /// Marks all declarations as `!important`.
+    pub(crate) fn important(self) -> Self {
+        self.map_static(|style| {
+            Style(style.0.map(|style| {
+                InnerStyle::Structured(
+                    style
+                        .into_structured()
+                        .into_iter()
+                        .map(|(key, value)| {
+                            (key, value.map(|value| StyleValue::important(value.value)))
+                        })
+                        .collect(),
+                )
+            }))
+        })
+    }
+
+    /// Sanitizes with `policy`, removing the whole style if it is rejected.
+    fn sanitize_or_default(self, policy: SanitizePolicy) -> Self {
+        self.sanitize(policy).unwrap_or_else(|err| {
+            error!("{err}");
+            Style::default()
+        })
+    }
+
+    /// Sanitizes with the [`SanitizePolicy`] from context, removing the whole style if it is rejected.
+    pub(crate) fn sanitize_with_context(self) -> Self {
+        self.sanitize_or_default(use_context::<SanitizePolicy>().unwrap_or_default())
+    }
+
+    /// Like [`Style::sanitize_with_context`], for declarations in a style sheet rule. Unsafe declarations are also
+    /// stripped with [`SanitizePolicy::Allow`], because they could end the rule.
+    pub(crate) fn sanitize_for_rule(self) -> Self {
+        let policy = match use_context::<SanitizePolicy>().unwrap_or_default() {
+            SanitizePolicy::Allow => SanitizePolicy::Strip,
+            policy => policy,
+        };
+        self.sanitize_or_default(policy)
+    }
+
+    /// Prepares the style for rendering: resolves token references with the [`Theme`](crate::Theme) from context and
+    /// sanitizes, see [`Style::sanitize_with_context`].
+    pub(crate) fn prepare_for_render(self) -> Self {
+        self.resolve_tokens_with_context().sanitize_with_context()
+    }
+
+    /// Prepares the style for a style sheet rule, see [`Style::prepare_for_render`] and [`Style::sanitize_for_rule`].
+    pub(crate) fn prepare_for_rule(self) -> Self {
+        self.resolve_tokens_with_context().sanitize_for_rule()
+    }
+
+    /// Sets a property, replacing an existing value. camelCase names are converted to kebab-case.
+    pub fn with_property<V: Into<StyleValue>>(self, name: &str, value: V) -> Self {
+        let name = normalize_property(name);
+        let value: StyleValue = value.into();
+
+        if self.is_reactive() {
+            let name = name.into_owned();
+            return self.map_static(move |style| style.with_property(&name, value.clone()));
+        }
+
+        Style(Some(match self.0 {
+            Some(InnerStyle::String(string)) => {
+                let string = string.trim_end();
+
+                InnerStyle::String(match string {
+                    "" => format!("{name}: {value};"),
+                    string if string.ends_with(';') => format!("{string} {name}: {value};"),
+                    string => format!("{string}; {name}: {value};"),
+                })
+            }
+            Some(InnerStyle::Structured(mut map)) => {
+                map.insert(Cow::Owned(name.into_owned()), Some(value));
+                InnerStyle::Structured(map)
+            }
+            None | Some(InnerStyle::Reactive(_) | InnerStyle::Conditional(_)) => {
+                InnerStyle::Structured(IndexMap::from([(
+                    Cow::Owned(name.into_owned()),
+                    Some(value),
+                )]))
+            }
+        }))
+    }
+
+    /// Converts to a structured style in place, parsing string styles, and returns its declarations.
+    pub(crate) fn structured_mut(&mut self) -> &mut StyleMap {
+        let map = self
+            .0
+            .take()
+            .map(InnerStyle::into_structured)
+            .unwrap_or_default();
+
+        match self.0.insert(InnerStyle::Structured(map)) {
+            InnerStyle::Structured(map) => map,
+            InnerStyle::String(_) | InnerStyle::Reactive(_) | InnerStyle::Conditional(_) => {
+                unreachable!("Style should be structured.")
+            }
+        }
+    }
+
+    /// Returns the value of a property, if it is set to a non-empty value. camelCase names are converted to
+    /// kebab-case.
+    ///
+    /// String styles are parsed, so the value is only borrowed for structured styles.
+    pub fn get(&self, name: &str) -> Option<Cow<'_, StyleValue>> {
+        let name = normalize_property(name);
+
+        match &self.0 {
+            Some(InnerStyle::String(string)) => parse_style_map(string)
+                .swap_remove(name.as_ref())
+                .flatten()
+                .filter(|value| !value.is_empty())
+                .map(Cow::Owned),
+            Some(InnerStyle::Structured(map)) => declared_value(map, &name).map(Cow::Borrowed),
+            Some(InnerStyle::Reactive(signal)) => signal
+                .with(|style| style.get(&name).map(Cow::into_owned))
+                .map(Cow::Owned),
+            Some(InnerStyle::Conditional(conditional)) => conditional
+                .current()
+                .get(&name)
+                .map(|value| Cow::Owned(value.into_owned())),
+            None => None,
+        }
+    }
+
+    /// Returns whether a property is set to a non-empty value.
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Iterates over the properties set to non-empty values, in declaration order.
+    ///
+    /// String styles are parsed, so the declarations are only borrowed for structured styles.
+    pub fn declarations(&self) -> impl Iterator<Item = (Cow<'_, str>, Cow<'_, StyleValue>)> {
+        let (parsed, map) = match &self.0 {
+            Some(InnerStyle::String(string)) => (Some(parse_style_map(string)), None),
+            Some(InnerStyle::Structured(map)) => (None, Some(map)),
+            Some(InnerStyle::Reactive(signal)) => (
+                Some(signal.with(|style| {
+                    style
+                        .declarations()
+                        .map(|(key, value)| {
+                            (Cow::Owned(key.into_owned()), Some(value.into_owned()))
+                        })
+                        .collect()
+                })),
+                None,
+            ),
+            Some(InnerStyle::Conditional(conditional)) => (
+                conditional.current().0.map(InnerStyle::into_structured),
+                None,
+            ),
+            None => (None, None),
+        };
+
+        parsed
+            .into_iter()
+            .flatten()
+            .filter_map(|(key, value)| {
+                value
+                    .filter(|value| !value.is_empty())
+                    .map(|value| (key, Cow::Owned(value)))
+            })
+            .chain(map.into_iter().flat_map(|map| {
+                map.keys().filter_map(|key| {
+                    declared_value(map, key)
+                        .map(|value| (Cow::Borrowed(key.as_ref()), Cow::Borrowed(value)))
+                })
+            }))
+    }
+
+    /// Sets a property, returning the previous value. camelCase names are converted to kebab-case.
+    ///
+    /// String styles are converted to structured styles.
+    pub fn set<K: Into<Cow<'static, str>>, V: Into<StyleValue>>(
+        &mut self,
+        name: K,
+        value: V,
+    ) -> Option<StyleValue> {
+        self.structured_mut()
+            .insert(normalize_key(name), Some(value.into()))
+            .flatten()
+            .filter(|value| !value.is_empty())
+    }
+
+    /// Removes a property, returning its value. camelCase names are converted to kebab-case.
+    ///
+    /// String styles are converted to structured styles.
+    pub fn remove(&mut self, name: &str) -> Option<StyleValue> {
+        self.0.as_ref()?;
+
+        self.structured_mut()
+            .shift_remove(normalize_property(name).as_ref())
+            .flatten()
+            .filter(|value| !value.is_empty())
+    }
+
+    /// Keeps only the properties for which `f` returns `true`. Unset properties are kept.
+    ///
+    /// String styles are converted to structured styles.
+    pub fn retain<F: FnMut(&str, &StyleValue) -> bool>(&mut self, mut f: F) {
+        if self.0.is_none() {
+            return;
+        }
+
+        self.structured_mut()
+            .retain(|key, value| value.as_ref().is_none_or(|value| f(key, value)));
+    }
+
+    /// Renders the declarations, adding vendor-prefixed declarations for the targets of `prefixer`.
+    pub fn to_prefixed_string(&self, prefixer: &Prefixer) -> String {
+        let mut string = String::new();
+        _ = self.write_prefixed(prefixer, &mut string);
+        string
+    }
+
+    pub(crate) fn write_prefixed<W: Write>(&self, prefixer: &Prefixer, f: &mut W) -> fmt::Result {
+        match &self.0 {
+            Some(InnerStyle::Reactive(signal)) => {
+                return signal.with(|style| style.write_prefixed(prefixer, f));
+            }
+            Some(InnerStyle::Conditional(conditional)) => {
+                return conditional.current().write_prefixed(prefixer, f);
+            }
+            _ => {}
+        }
+
+        let mut first = true;
+        let mut write_declaration = |key: &str, value: &StyleValue| {
+            for (key, value) in prefixer.prefix_declaration(key, value) {
+                if !first {
+                    f.write_char(' ')?;
+                }
+                first = false;
+
+                write!(f, "{key}: {value};")?;
+            }
+            Ok(())
+        };
+
+        match &self.0 {
+            Some(InnerStyle::String(string)) => {
+                for (key, value) in parse_declarations(string) {
+                    write_declaration(&key, &StyleValue::from(value))?;
+                }
+            }
+            Some(InnerStyle::Structured(map)) => {
+                for key in map.keys() {
+                    if let Some(value) = declared_value(map, key) {
+                        write_declaration(key, value)?;
+                    }
+                }
+            }
+            Some(InnerStyle::Reactive(_) | InnerStyle::Conditional(_)) | None => {}
+        }
+        Ok(())
+    }
+
+    /// Renders the declarations for the DOM, using the [`StyleFormat`] and the [`Prefixer`] from context if there
+    /// are any.
+    fn write_rendered<W: Write>(&self, f: &mut W) -> fmt::Result {
+        self.write_formatted(
+            use_context::<StyleFormat>().unwrap_or_default(),
+            use_context::<Prefixer>().as_ref(),
+            f,
+        )
+    }
+
+    pub(crate) fn to_rendered_string(&self) -> String {
+        let mut string = String::new();
+        _ = self.write_rendered(&mut string);
+        string
+    }
+
+    /// Sets a property to a number, appending `px` for dimensional properties, see [`number_value`].
+    pub fn with_number(self, name: &str, number: f64) -> Self {
+        self.with_property(name, number_value(name, number))
+    }
+
+    /// Sets a custom property, adding the `--` prefix to `name` if it is missing.
+    pub fn with_custom_property<V: Into<StyleValue>>(self, name: &str, value: V) -> Self {
+        self.with_property(&custom_property_name(name), value)
+    }
+
+    /// Returns the value of a custom property, adding the `--` prefix to `name` if it is missing.
+    pub fn custom_property(&self, name: &str) -> Option<String> {
+        self.custom_properties()
+            .shift_remove(&custom_property_name(name))
+    }
+
+    /// Returns the custom properties defined in this style, in declaration order.
+    pub fn custom_properties(&self) -> IndexMap<String, String> {
+        self.0
+            .as_ref()
+            .map(InnerStyle::custom_properties)
+            .unwrap_or_default()
+    }
 }
 
 impl Deref for Style {
@@ -83,14 +836,52 @@ impl Deref for Style {
 
 impl Display for Style {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            self.0
-                .as_ref()
-                .map(|inner_style| inner_style.to_string())
-                .unwrap_or_default(),
-        )
+        match &self.0 {
+            Some(inner_style) => inner_style.fmt(f),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<K: Into<Cow<'static, str>>, V: Into<StyleValue>> Extend<(K, V)> for Style {
+    /// Sets each property, see [`Style::set`].
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        let map = self.structured_mut();
+        for (name, value) in iter {
+            map.insert(normalize_key(name), Some(value.into()));
+        }
+    }
+}
+
+impl<K: Into<Cow<'static, str>>, V: Into<StyleValue>> FromIterator<(K, V)> for Style {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut style = Style::new();
+        style.extend(iter);
+        style
+    }
+}
+
+impl From<Signal<Style>> for Style {
+    fn from(value: Signal<Style>) -> Style {
+        Style(Some(InnerStyle::Reactive(value)))
+    }
+}
+
+impl From<ReadSignal<Style>> for Style {
+    fn from(value: ReadSignal<Style>) -> Style {
+        Style::from(Signal::from(value))
+    }
+}
+
+impl From<RwSignal<Style>> for Style {
+    fn from(value: RwSignal<Style>) -> Style {
+        Style::from(Signal::from(value))
+    }
+}
+
+impl From<Memo<Style>> for Style {
+    fn from(value: Memo<Style>) -> Style {
+        Style::from(Signal::from(value))
     }
 }
 
@@ -118,9 +909,36 @@ impl From<String> for Style {
     }
 }
 
+impl From<IndexMap<String, Option<StyleValue>>> for Style {
+    fn from(value: IndexMap<String, Option<StyleValue>>) -> Style {
+        Style(Some(InnerStyle::Structured(
+            value
+                .into_iter()
+                .map(|(key, value)| (normalize_key(key), value))
+                .collect(),
+        )))
+    }
+}
+
+impl From<StyleMap> for Style {
+    fn from(value: StyleMap) -> Style {
+        Style(Some(InnerStyle::Structured(
+            value
+                .into_iter()
+                .map(|(key, value)| (normalize_key(key), value))
+                .collect(),
+        )))
+    }
+}
+
 impl From<IndexMap<String, Option<String>>> for Style {
     fn from(value: IndexMap<String, Option<String>>) -> Style {
-        Style(Some(InnerStyle::Structured(value)))
+        Style(Some(InnerStyle::Structured(
+            value
+                .into_iter()
+                .map(|(key, value)| (normalize_key(key), value.map(StyleValue::from)))
+                .collect(),
+        )))
     }
 }
 
@@ -129,7 +947,7 @@ impl From<IndexMap<String, String>> for Style {
         Style(Some(InnerStyle::Structured(
             value
                 .into_iter()
-                .map(|(key, value)| (key, Some(value)))
+                .map(|(key, value)| (normalize_key(key), Some(StyleValue::from(value))))
                 .collect(),
         )))
     }
@@ -138,7 +956,7 @@ impl From<IndexMap<String, String>> for Style {
 impl<const N: usize> From<[(&str, Option<&str>); N]> for Style {
     fn from(value: [(&str, Option<&str>); N]) -> Style {
         Style(Some(InnerStyle::Structured(IndexMap::from_iter(
-            value.map(|(key, value)| (key.to_string(), value.map(|value| value.to_string()))),
+            value.map(|(key, value)| (owned_key(key), value.map(StyleValue::from))),
         ))))
     }
 }
@@ -146,7 +964,7 @@ impl<const N: usize> From<[(&str, Option<&str>); N]> for Style {
 impl<const N: usize> From<[(&str, &str); N]> for Style {
     fn from(value: [(&str, &str); N]) -> Style {
         Style(Some(InnerStyle::Structured(IndexMap::from_iter(
-            value.map(|(key, value)| (key.to_string(), Some(value.to_string()))),
+            value.map(|(key, value)| (owned_key(key), Some(StyleValue::from(value)))),
         ))))
     }
 }
@@ -154,7 +972,7 @@ impl<const N: usize> From<[(&str, &str); N]> for Style {
 impl<const N: usize> From<[(&str, Option<String>); N]> for Style {
     fn from(value: [(&str, Option<String>); N]) -> Style {
         Style(Some(InnerStyle::Structured(IndexMap::from_iter(
-            value.map(|(key, value)| (key.to_string(), value)),
+            value.map(|(key, value)| (owned_key(key), value.map(StyleValue::from))),
         ))))
     }
 }
@@ -162,53 +980,424 @@ impl<const N: usize> From<[(&str, Option<String>); N]> for Style {
 impl<const N: usize> From<[(&str, String); N]> for Style {
     fn from(value: [(&str, String); N]) -> Style {
         Style(Some(InnerStyle::Structured(IndexMap::from_iter(
-            value.map(|(key, value)| (key.to_string(), Some(value))),
+            value.map(|(key, value)| (owned_key(key), Some(StyleValue::from(value)))),
+        ))))
+    }
+}
+
+impl<const N: usize> From<[(&str, Option<StyleValue>); N]> for Style {
+    fn from(value: [(&str, Option<StyleValue>); N]) -> Style {
+        Style(Some(InnerStyle::Structured(IndexMap::from_iter(
+            value.map(|(key, value)| (owned_key(key), value)),
+        ))))
+    }
+}
+
+impl<const N: usize> From<[(&str, StyleValue); N]> for Style {
+    fn from(value: [(&str, StyleValue); N]) -> Style {
+        Style(Some(InnerStyle::Structured(IndexMap::from_iter(
+            value.map(|(key, value)| (owned_key(key), Some(value))),
+        ))))
+    }
+}
+
+/// Borrows static kebab-case keys and values instead of copying them, e.g. `(Cow::Borrowed("color"),
+/// StyleValue::new("red"))`. This is what the `style!` macro generates.
+impl<const N: usize> From<[(Cow<'static, str>, StyleValue); N]> for Style {
+    fn from(value: [(Cow<'static, str>, StyleValue); N]) -> Style {
+        Style(Some(InnerStyle::Structured(IndexMap::from_iter(
+            value.map(|(key, value)| (normalize_key(key), Some(value))),
         ))))
     }
 }
 
 impl<const N: usize> From<[(String, Option<String>); N]> for Style {
     fn from(value: [(String, Option<String>); N]) -> Style {
-        Style(Some(InnerStyle::Structured(IndexMap::from_iter(value))))
+        Style(Some(InnerStyle::Structured(IndexMap::from_iter(
+            value.map(|(key, value)| (normalize_key(key), value.map(StyleValue::from))),
+        ))))
     }
 }
 
 impl<const N: usize> From<[(String, String); N]> for Style {
     fn from(value: [(String, String); N]) -> Style {
         Style(Some(InnerStyle::Structured(IndexMap::from_iter(
-            value.map(|(key, value)| (key, Some(value))),
+            value.map(|(key, value)| (normalize_key(key), Some(StyleValue::from(value)))),
         ))))
     }
 }
 
-impl IntoStyle for Style {
-    type AsyncOutput = Self;
-    type State = (leptos::tachys::renderer::types::Element, Self);
-    type Cloneable = Self;
-    type CloneableOwned = Self;
-
-    fn to_html(self, style: &mut String) {
-        style.push_str(&self.to_string());
+impl<const N: usize> From<[(&str, f64); N]> for Style {
+    /// Converts numbers with [`number_value`], appending `px` for dimensional properties.
+    fn from(value: [(&str, f64); N]) -> Style {
+        Style(Some(InnerStyle::Structured(IndexMap::from_iter(
+            value.map(|(key, value)| {
+                (
+                    owned_key(key),
+                    Some(StyleValue::new(number_value(key, value))),
+                )
+            }),
+        ))))
     }
+}
 
-    fn hydrate<const FROM_SERVER: bool>(
-        self,
-        el: &leptos::tachys::renderer::types::Element,
-    ) -> Self::State {
-        (el.clone(), self)
+impl<const N: usize> From<[(&str, i32); N]> for Style {
+    /// Converts numbers with [`number_value`], appending `px` for dimensional properties.
+    fn from(value: [(&str, i32); N]) -> Style {
+        Style::from(value.map(|(key, value)| (key, f64::from(value))))
     }
+}
 
-    fn build(self, el: &leptos::tachys::renderer::types::Element) -> Self::State {
-        leptos::tachys::renderer::Rndr::set_attribute(el, "style", &self.to_string());
-        (el.clone(), self)
-    }
+/// The rendering state of a [`Style`].
+pub struct StyleState {
+    el: Element,
+    rendered: RenderedStyle,
+}
 
-    fn rebuild(self, state: &mut Self::State) {
-        let (el, prev) = state;
-        if self != *prev {
-            leptos::tachys::renderer::Rndr::set_attribute(el, "style", &self.to_string());
+enum RenderedStyle {
+    Static(Style),
+    Reactive(RenderEffect<Style>),
+    Conditional(RenderEffect<ConditionalRender>),
+}
+
+/// Property updates from a rendered structured style to another one.
+struct StylePatch<'a> {
+    removed: Vec<&'a str>,
+    changed: Vec<(&'a str, &'a StyleValue)>,
+}
+
+/// Returns whether setting or removing `property` also changes `other`, because one is a shorthand of the other
+/// (e.g. `margin` and `margin-top`).
+fn overlaps(property: &str, other: &str) -> bool {
+    let is_longhand = |shorthand: &str, longhand: &str| {
+        longhand
+            .strip_prefix(shorthand)
+            .is_some_and(|rest| rest.starts_with('-'))
+            || longhands(shorthand).is_some_and(|longhands| longhands.iter().any(|l| l == longhand))
+    };
+
+    property != other
+        && !is_custom_property(property)
+        && !is_custom_property(other)
+        && (is_longhand(property, other) || is_longhand(other, property))
+}
+
+/// Returns the property updates that turn the rendered `prev` into `map`.
+///
+/// Returns `None` if the whole attribute has to be set instead: if an updated property overlaps another declared
+/// property, which it would also reset, or if the declaration order differs from the order the updates produce.
+fn style_patch<'a>(prev: &'a StyleMap, map: &'a StyleMap) -> Option<StylePatch<'a>> {
+    let declared = |map: &'a StyleMap| {
+        map.iter()
+            .filter_map(|(key, value)| {
+                value
+                    .as_ref()
+                    .filter(|value| !value.is_empty())
+                    .map(|value| (key.as_ref(), value))
+            })
+            .collect::<Vec<_>>()
+    };
+    let (prev_declared, declared) = (declared(prev), declared(map));
+
+    let removed = prev_declared
+        .iter()
+        .filter(|(key, _)| declared_value(map, key).is_none())
+        .map(|(key, _)| *key)
+        .collect::<Vec<_>>();
+    let changed = declared
+        .iter()
+        .filter(|(key, value)| declared_value(prev, key) != Some(*value))
+        .copied()
+        .collect::<Vec<_>>();
+
+    // Updated properties keep their position, added properties are appended.
+    let patched_order = prev_declared
+        .iter()
+        .map(|(key, _)| *key)
+        .filter(|key| declared_value(map, key).is_some())
+        .chain(
+            declared
+                .iter()
+                .map(|(key, _)| *key)
+                .filter(|key| declared_value(prev, key).is_none()),
+        );
+    if !patched_order.eq(declared.iter().map(|(key, _)| *key)) {
+        return None;
+    }
+
+    let updated = removed
+        .iter()
+        .copied()
+        .chain(changed.iter().map(|(key, _)| *key));
+    if overlaps_declared(updated, map) {
+        return None;
+    }
+
+    Some(StylePatch { removed, changed })
+}
+
+/// Returns whether one of the `updated` properties overlaps a property declared in `map`, see [`overlaps`].
+fn overlaps_declared<'a>(mut updated: impl Iterator<Item = &'a str>, map: &StyleMap) -> bool {
+    updated.any(|key| {
+        map.keys()
+            .any(|other| declared_value(map, other).is_some() && overlaps(key, other))
+    })
+}
+
+/// Returns the updates of `keys` to their values in the rendered `map`, or `None` if an updated property overlaps
+/// another declared property.
+fn keys_patch<'a>(keys: &'a [Cow<'static, str>], map: &'a StyleMap) -> Option<StylePatch<'a>> {
+    let (mut removed, mut changed) = (vec![], vec![]);
+    for key in keys {
+        match declared_value(map, key) {
+            Some(value) => changed.push((key.as_ref(), value)),
+            None => removed.push(key.as_ref()),
+        }
+    }
+
+    if overlaps_declared(keys.iter().map(AsRef::as_ref), map) {
+        return None;
+    }
+
+    Some(StylePatch { removed, changed })
+}
+
+/// Applies property updates to the inline style of `el`.
+fn apply_patch(el: &Element, StylePatch { removed, changed }: StylePatch<'_>) {
+    // `setProperty` and `removeProperty` accept custom property names as-is,
+    // unlike camel-cased `CSSStyleDeclaration` fields.
+    let declaration = Rndr::style(el);
+    let prefixer = use_context::<Prefixer>().unwrap_or_default();
+
+    for key in removed {
+        for key in prefixer
+            .prefixed_properties(key)
+            .iter()
+            .map(String::as_str)
+            .chain([key])
+        {
+            Rndr::remove_css_property(&declaration, key);
+        }
+    }
+
+    for (key, value) in changed {
+        // `Rndr::set_css_property` has no priority argument.
+        for (key, value) in prefixer.prefix_declaration(key, value) {
+            _ = declaration.set_property_with_priority(&key, &value, value.priority());
+        }
+    }
+}
+
+impl Style {
+    fn hydrate_static<const FROM_SERVER: bool>(self, _el: &Element) -> Style {
+        #[cfg(feature = "hoist")]
+        if FROM_SERVER {
+            crate::hoist::restore(_el, &self);
+        }
+
+        let style = self.prepare_for_render();
+        #[cfg(feature = "debug")]
+        crate::validate::log_diagnostics(&style);
+
+        #[cfg(feature = "debug")]
+        if FROM_SERVER {
+            if let Some(server) =
+                crate::hydration::check_hydration(_el, &style.to_rendered_string())
+            {
+                // Diffing the next update against the kept server-rendered style replaces it as a whole.
+                return Style::from(server);
+            }
+        }
+
+        style
+    }
+
+    fn build_static(self, el: &Element) -> Style {
+        let style = self.prepare_for_render();
+        #[cfg(feature = "debug")]
+        crate::validate::log_diagnostics(&style);
+
+        Rndr::set_attribute(el, "style", &style.to_rendered_string());
+        style
+    }
+
+    fn rebuild_static(self, el: &Element, prev: &mut Style) {
+        let style = self.prepare_for_render();
+        if !style.same_declarations(prev) {
+            #[cfg(feature = "debug")]
+            crate::validate::log_diagnostics(&style);
+
+            let patch = match (&prev.0, &style.0) {
+                (Some(InnerStyle::Structured(prev_map)), Some(InnerStyle::Structured(map))) => {
+                    style_patch(prev_map, map)
+                }
+                _ => None,
+            };
+
+            match patch {
+                Some(patch) => apply_patch(el, patch),
+                None => Rndr::set_attribute(el, "style", &style.to_rendered_string()),
+            }
+        }
+        *prev = style;
+    }
+}
+
+/// Renders each value of a reactive style, rebuilding the previous value.
+///
+/// `first` renders the first value if there is no previous value.
+fn render_effect(
+    el: &Element,
+    signal: Signal<Style>,
+    prev: Option<Style>,
+    first: fn(Style, &Element) -> Style,
+) -> RenderEffect<Style> {
+    let el = el.clone();
+
+    RenderEffect::new_with_value(
+        move |prev| {
+            let style = signal.get().into_current();
+            match prev {
+                Some(mut prev) => {
+                    style.rebuild_static(&el, &mut prev);
+                    prev
+                }
+                None => first(style, &el),
+            }
+        },
+        prev,
+    )
+}
+
+/// Renders each value of a conditional style. If only conditions changed, only the declarations of the toggled
+/// entries are updated.
+fn conditional_effect(
+    el: &Element,
+    conditional: ConditionalStyle,
+    prev: Option<Style>,
+    first: fn(Style, &Element) -> Style,
+) -> RenderEffect<ConditionalRender> {
+    let el = el.clone();
+
+    RenderEffect::new_with_value(
+        move |render| {
+            let base = conditional.base.current();
+            let active = conditional.active();
+
+            match render {
+                Some(mut render) => {
+                    if let Some(keys) = render.toggle(&base, active.clone()) {
+                        let map = &*render.style.structured_mut();
+                        match keys_patch(&keys, map) {
+                            Some(patch) => apply_patch(&el, patch),
+                            None => Rndr::set_attribute(
+                                &el,
+                                "style",
+                                &render.style.to_rendered_string(),
+                            ),
+                        }
+                        return render;
+                    }
+
+                    conditional
+                        .with_active(base.clone(), &active)
+                        .rebuild_static(&el, &mut render.style);
+                    render.rendered_from(base, &conditional, active);
+                    render
+                }
+                None => {
+                    let style = conditional.with_active(base.clone(), &active);
+                    let mut render = ConditionalRender::new(first(style.clone(), &el));
+
+                    // A kept mismatched server style is not recorded as rendered from the active entries, so the
+                    // next update is not a toggle and replaces it as a whole.
+                    let kept_mismatch =
+                        !render.style.same_declarations(&style.prepare_for_render());
+                    if !kept_mismatch {
+                        render.rendered_from(base, &conditional, active);
+                    }
+                    render
+                }
+            }
+        },
+        prev.map(ConditionalRender::new),
+    )
+}
+
+impl IntoStyle for Style {
+    type AsyncOutput = Self;
+    type State = StyleState;
+    type Cloneable = Self;
+    type CloneableOwned = Self;
+
+    fn to_html(self, style: &mut String) {
+        let current = self.into_current();
+        #[cfg(feature = "hoist")]
+        if crate::hoist::hoist(&current, style) {
+            return;
+        }
+
+        let rendered = current.prepare_for_render();
+        #[cfg(feature = "debug")]
+        crate::validate::log_diagnostics(&rendered);
+
+        _ = rendered.write_rendered(style);
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(self, el: &Element) -> Self::State {
+        StyleState {
+            el: el.clone(),
+            rendered: match self.0 {
+                Some(InnerStyle::Reactive(signal)) => RenderedStyle::Reactive(render_effect(
+                    el,
+                    signal,
+                    None,
+                    Style::hydrate_static::<FROM_SERVER>,
+                )),
+                Some(InnerStyle::Conditional(conditional)) => RenderedStyle::Conditional(
+                    conditional_effect(el, conditional, None, Style::hydrate_static::<FROM_SERVER>),
+                ),
+                _ => RenderedStyle::Static(self.hydrate_static::<FROM_SERVER>(el)),
+            },
+        }
+    }
+
+    fn build(self, el: &Element) -> Self::State {
+        StyleState {
+            el: el.clone(),
+            rendered: match self.0 {
+                Some(InnerStyle::Reactive(signal)) => {
+                    RenderedStyle::Reactive(render_effect(el, signal, None, Style::build_static))
+                }
+                Some(InnerStyle::Conditional(conditional)) => RenderedStyle::Conditional(
+                    conditional_effect(el, conditional, None, Style::build_static),
+                ),
+                _ => RenderedStyle::Static(self.build_static(el)),
+            },
         }
-        *prev = self;
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        let StyleState { el, rendered } = state;
+        let prev = match rendered {
+            RenderedStyle::Static(prev) => Some(mem::take(prev)),
+            RenderedStyle::Reactive(effect) => effect.take_value(),
+            RenderedStyle::Conditional(effect) => effect.take_value().map(|render| render.style),
+        };
+
+        *rendered = match (self.0, prev) {
+            (Some(InnerStyle::Reactive(signal)), prev) => {
+                RenderedStyle::Reactive(render_effect(el, signal, prev, Style::build_static))
+            }
+            (Some(InnerStyle::Conditional(conditional)), prev) => RenderedStyle::Conditional(
+                conditional_effect(el, conditional, prev, Style::build_static),
+            ),
+            (style, Some(mut prev)) => {
+                Style(style).rebuild_static(el, &mut prev);
+                RenderedStyle::Static(prev)
+            }
+            (style, None) => RenderedStyle::Static(Style(style).build_static(el)),
+        };
     }
 
     fn into_cloneable(self) -> Self::Cloneable {
@@ -219,21 +1408,35 @@ impl IntoStyle for Style {
         self
     }
 
-    fn dry_resolve(&mut self) {}
+    fn dry_resolve(&mut self) {
+        match &mut self.0 {
+            Some(InnerStyle::Reactive(signal)) => signal.track(),
+            Some(InnerStyle::Conditional(conditional)) => {
+                conditional.base.dry_resolve();
+                for (condition, _) in &conditional.entries {
+                    condition.track();
+                }
+            }
+            _ => {}
+        }
+    }
 
     async fn resolve(self) -> Self::AsyncOutput {
         self
     }
 
     fn reset(state: &mut Self::State) {
-        let (el, _prev) = state;
-        leptos::tachys::renderer::Rndr::remove_attribute(el, "style");
+        Rndr::remove_attribute(&state.el, "style");
+        state.rendered = RenderedStyle::Static(Style::default());
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use leptos::prelude::{provide_context, Owner, Set};
+
     use super::*;
+    use crate::prefix::PrefixTarget;
 
     #[test]
     fn test_to_string() {
@@ -332,4 +1535,594 @@ mod tests {
             Style::from([("color", None::<String>)]).with_defaults([("color", None::<String>)]),
         );
     }
+
+    #[test]
+    fn test_with_defaults_expanded() {
+        // Without expansion, the default `margin-top` overrides the user's `margin`
+        assert_eq!(
+            "margin: 1rem; margin-top: 2rem;",
+            Style::from([("margin", "1rem")])
+                .with_defaults([("margin", "0"), ("margin-top", "2rem")])
+                .to_string(),
+        );
+        assert_eq!(
+            "margin: 1rem;",
+            Style::from([("margin", "1rem")])
+                .with_defaults_expanded([("margin", "0"), ("margin-top", "2rem")])
+                .collapse_shorthands()
+                .to_string(),
+        );
+
+        assert_eq!(
+            Style::from([
+                ("margin-top", "1rem"),
+                ("margin-right", "0"),
+                ("margin-bottom", "0"),
+                ("margin-left", "0"),
+            ]),
+            Style::from([("margin-top", "1rem")]).with_defaults_expanded([("margin", "0")]),
+        );
+        assert_eq!(
+            Style::from([("margin", "1rem 0 0")]),
+            Style::from([("margin-top", "1rem")])
+                .with_defaults_expanded([("margin", "0")])
+                .collapse_shorthands(),
+        );
+        assert_eq!(
+            Style::from([("padding", "1rem"), ("color", "red")]),
+            Style::from("padding-left: 1rem; padding-right: 1rem;")
+                .with_defaults_expanded("padding: 1rem 0; color: red;")
+                .collapse_shorthands(),
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let base = || {
+            Style::from([
+                ("color", "red"),
+                ("padding", "1rem !important"),
+                ("transition", "opacity 0.2s"),
+            ])
+        };
+        let other = || {
+            Style::from([
+                ("color", "blue"),
+                ("padding", "2rem"),
+                ("margin", "0"),
+                ("transition", "color 1s"),
+            ])
+        };
+
+        // Override: other wins, except over important values
+        assert_eq!(
+            Style::from([
+                ("color", "blue"),
+                ("padding", "1rem !important"),
+                ("transition", "color 1s"),
+                ("margin", "0"),
+            ]),
+            base().merge(other(), MergeStrategy::Override),
+        );
+        assert_eq!(
+            other().with_defaults(base()),
+            base().merge(other(), MergeStrategy::Override),
+        );
+
+        // Preserve: base wins, other only adds missing properties
+        assert_eq!(
+            Style::from([
+                ("color", "red"),
+                ("padding", "1rem !important"),
+                ("transition", "opacity 0.2s"),
+                ("margin", "0"),
+            ]),
+            base().merge(other(), MergeStrategy::Preserve),
+        );
+        assert_eq!(
+            Style::from([("color", "blue !important")]),
+            Style::from([("color", "red")])
+                .merge([("color", "blue !important")], MergeStrategy::Preserve),
+        );
+
+        // Remove: properties of other are removed, except important values
+        assert_eq!(
+            Style::from([("padding", "1rem !important")]),
+            base().merge(other(), MergeStrategy::Remove),
+        );
+        assert_eq!(
+            Style::from([("padding", "1rem")]),
+            Style::from("color: red; padding: 1rem;")
+                .merge([("color", None::<String>)], MergeStrategy::Remove),
+        );
+        assert_eq!(
+            Style::default(),
+            Style::default().merge(other(), MergeStrategy::Remove),
+        );
+
+        // Combine: list values are combined, other values are overridden
+        assert_eq!(
+            Style::from([
+                ("color", "blue"),
+                ("padding", "1rem !important"),
+                ("transition", "opacity 0.2s, color 1s"),
+                ("margin", "0"),
+            ]),
+            base().merge(other(), MergeStrategy::Combine),
+        );
+        assert_eq!(
+            Style::from([("transform", "translateX(1px) rotate(1deg)")]),
+            Style::from("transform: translateX(1px)")
+                .merge("transform: rotate(1deg)", MergeStrategy::Combine),
+        );
+
+        // Component-enforced properties
+        assert_eq!(
+            Style::from([("pointer-events", "none"), ("color", "blue")]),
+            Style::from([("color", "blue"), ("pointer-events", "auto")])
+                .with_defaults([("pointer-events", "auto")])
+                .merge([("pointer-events", "none")], MergeStrategy::Override),
+        );
+    }
+
+    #[test]
+    fn test_important() {
+        assert_eq!(
+            "color: red !important; padding: 1rem;",
+            Style::from([
+                ("color", StyleValue::important("red")),
+                ("padding", StyleValue::new("1rem")),
+            ])
+            .to_string(),
+        );
+        assert_eq!(
+            Style::from([("color", StyleValue::important("red"))]),
+            Style::from([("color", "red !important")]),
+        );
+
+        // Important defaults are not overridden by normal values
+        assert_eq!(
+            Style::from([("color", "blue !important"), ("padding", "2rem")]),
+            Style::from([("color", "red"), ("padding", "2rem")])
+                .with_defaults([("color", "blue !important"), ("padding", "1rem")]),
+        );
+        assert_eq!(
+            Style::from([("color", "red !important")]),
+            Style::from([("color", "red !important")])
+                .with_defaults([("color", "blue !important")]),
+        );
+        assert_eq!(
+            Style::from([("color", Some("blue !important"))]),
+            Style::from([("color", None::<String>)])
+                .with_defaults([("color", Some("blue !important"))]),
+        );
+        assert_eq!(
+            Style::from([("color", "red !important")]),
+            Style::from([("color", "red !important")]).with_defaults([("color", "blue")]),
+        );
+    }
+
+    #[test]
+    fn test_sanitize() {
+        assert_eq!(
+            Ok(Style::from("color: red; padding: 1rem;")),
+            Style::from("color: red; padding: 1rem; }</style>").sanitize(SanitizePolicy::Strip),
+        );
+        assert_eq!(
+            Ok(Style::from([("padding", "1rem !important")])),
+            Style::from([
+                ("color", "red; position: fixed"),
+                ("padding", "1rem !important")
+            ])
+            .sanitize(SanitizePolicy::Strip),
+        );
+        assert_eq!(
+            Ok(Style::from([("color", "red\\3b  position: fixed")])),
+            Style::from([("color", "red; position: fixed")]).sanitize(SanitizePolicy::Escape),
+        );
+        assert_eq!(
+            Some("color".to_string()),
+            Style::from([("color", "url(javascript:alert(1))")])
+                .sanitize(SanitizePolicy::Error)
+                .err()
+                .map(|error| error.property),
+        );
+        assert_eq!(
+            Ok(Style::default()),
+            Style::default().sanitize(SanitizePolicy::Error)
+        );
+    }
+
+    #[test]
+    fn test_to_html_sanitize() {
+        let owner = Owner::new();
+        owner.set();
+
+        let style = Style::from([("color", "red\" onclick=alert(1)"), ("padding", "1rem")]);
+
+        // Unsafe declarations are stripped by default.
+        let mut html = String::new();
+        style.clone().to_html(&mut html);
+        assert_eq!("padding: 1rem;", html);
+
+        let mut html = String::new();
+        Style::from([("color", "red; position: fixed")]).to_html(&mut html);
+        assert_eq!("", html);
+
+        let mut html = String::new();
+        Style::from("color:red;  } body { color: blue").to_html(&mut html);
+        assert_eq!("color: red;", html);
+
+        provide_context(SanitizePolicy::Allow);
+        let mut html = String::new();
+        style.clone().to_html(&mut html);
+        assert_eq!("color: red\" onclick=alert(1); padding: 1rem;", html);
+
+        provide_context(SanitizePolicy::Escape);
+        let mut html = String::new();
+        style.clone().to_html(&mut html);
+        assert_eq!(
+            "color: red\\22  onclick=alert\\28 1\\29 ; padding: 1rem;",
+            html
+        );
+
+        provide_context(SanitizePolicy::Error);
+        let mut html = String::new();
+        style.to_html(&mut html);
+        assert_eq!("", html);
+    }
+
+    #[test]
+    fn test_custom_properties() {
+        assert_eq!(
+            Style::from([("--primary", "blue")]),
+            Style::new().with_custom_property("primary", "blue"),
+        );
+        assert_eq!(
+            Style::from("color: var(--primary); --primary: blue;"),
+            Style::from("color: var(--primary)").with_custom_property("--primary", "blue"),
+        );
+
+        assert_eq!(
+            Some("blue".to_string()),
+            Style::from([("color", "var(--primary)"), ("--primary", "blue")])
+                .custom_property("primary"),
+        );
+        assert_eq!(
+            Some("red".to_string()),
+            Style::from("--primary: blue; color: var(--primary); --primary: red;")
+                .custom_property("--primary"),
+        );
+        assert_eq!(None, Style::from("color: red;").custom_property("color"));
+
+        // Definitions are ordered before uses when merging
+        assert_eq!(
+            "--radius: 4px; --primary: red; color: var(--primary); border-radius: var(--radius);",
+            Style::from([("--primary", "red")])
+                .with_defaults([
+                    ("color", "var(--primary)"),
+                    ("border-radius", "var(--radius)"),
+                    ("--radius", "4px"),
+                ])
+                .to_string(),
+        );
+    }
+
+    #[test]
+    fn test_to_html_prefix() {
+        let owner = Owner::new();
+        owner.set();
+
+        let style = Style::from([("user-select", "none"), ("width", "stretch")]);
+
+        let mut html = String::new();
+        style.clone().to_html(&mut html);
+        assert_eq!("user-select: none; width: stretch;", html);
+
+        provide_context(Prefixer::new([PrefixTarget::Safari]));
+        let mut html = String::new();
+        style.to_html(&mut html);
+        assert_eq!(
+            "-webkit-user-select: none; user-select: none; width: -webkit-fill-available; width: stretch;",
+            html
+        );
+
+        assert_eq!(
+            "-webkit-backdrop-filter: blur(4px); backdrop-filter: blur(4px);",
+            Style::from("backdrop-filter: blur(4px)")
+                .to_prefixed_string(&Prefixer::new([PrefixTarget::Safari]))
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            "background-color: red; -webkit-transform: none; --myColor: blue;",
+            Style::from([
+                ("backgroundColor", "red"),
+                ("WebkitTransform", "none"),
+                ("--myColor", "blue"),
+            ])
+            .to_string()
+        );
+        assert_eq!(
+            "width: 100px; opacity: 0.5; margin-top: 0; z-index: 10;",
+            Style::from([
+                ("width", 100.0),
+                ("opacity", 0.5),
+                ("marginTop", 0.0),
+                ("zIndex", 10.0),
+            ])
+            .to_string()
+        );
+        assert_eq!(
+            "height: 24px; flex-grow: 1;",
+            Style::from([("height", 24), ("flexGrow", 1)]).to_string()
+        );
+        assert_eq!(
+            "border-top-width: 2px; font-weight: 600;",
+            Style::new()
+                .with_number("borderTopWidth", 2.0)
+                .with_number("fontWeight", 600.0)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        for style in [
+            Style::from("color: red; margin: 0 !important; color: blue; padding: ;"),
+            Style::from([
+                ("color", Some("blue")),
+                ("margin", Some("0 !important")),
+                ("padding", Some("")),
+                ("border", None),
+            ]),
+        ] {
+            assert_eq!(
+                Some(StyleValue::new("blue")),
+                style.get("color").as_deref().cloned()
+            );
+            assert_eq!(
+                Some(StyleValue::important("0")),
+                style.get("margin").as_deref().cloned()
+            );
+            assert!(style.contains("color"));
+            assert!(!style.contains("padding"));
+            assert!(!style.contains("border"));
+            assert_eq!(
+                vec!["color: blue", "margin: 0 !important"],
+                style
+                    .declarations()
+                    .map(|(key, value)| format!("{key}: {value}"))
+                    .collect::<Vec<_>>(),
+                "{style}"
+            );
+        }
+
+        assert_eq!(None, Style::default().get("color"));
+        assert_eq!(0, Style::default().declarations().count());
+    }
+
+    #[test]
+    fn test_set_remove() {
+        let mut style = Style::from("color: red; margin: 0;");
+        assert_eq!(Some(StyleValue::new("red")), style.set("color", "blue"));
+        assert_eq!(None, style.set("backgroundColor", "white"));
+        assert_eq!(
+            "color: blue; margin: 0; background-color: white;",
+            style.to_string()
+        );
+
+        assert_eq!(Some(StyleValue::new("0")), style.remove("margin"));
+        assert_eq!(None, style.remove("margin"));
+        assert_eq!(
+            Some(StyleValue::new("white")),
+            style.remove("backgroundColor")
+        );
+        assert_eq!("color: blue;", style.to_string());
+
+        let mut style = Style::default();
+        assert_eq!(None, style.remove("color"));
+        assert_eq!(Style::default(), style);
+        style.set("color", format!("#{:06x}", 0xff0000));
+        assert_eq!("color: #ff0000;", style.to_string());
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut style = Style::from([
+            ("--accent", Some("blue")),
+            ("color", Some("var(--accent)")),
+            ("margin", None),
+            ("padding", Some("1rem !important")),
+        ]);
+        style.retain(|key, value| !is_custom_property(key) && !value.important);
+        assert_eq!(
+            Style::from([("color", Some("var(--accent)")), ("margin", None)]),
+            style
+        );
+
+        let mut style = Style::from("color: red; padding: 1rem;");
+        style.retain(|key, _| key == "padding");
+        assert_eq!("padding: 1rem;", style.to_string());
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let style = [("color", "red"), ("marginTop", "1rem")]
+            .into_iter()
+            .collect::<Style>();
+        assert_eq!(
+            Style::from([("color", "red"), ("margin-top", "1rem")]),
+            style
+        );
+
+        let mut style = Style::from("color: red;");
+        style.extend((1..=2).map(|index| (format!("--level-{index}"), index.to_string())));
+        assert_eq!("color: red; --level-1: 1; --level-2: 2;", style.to_string());
+    }
+
+    #[test]
+    fn test_style_patch() {
+        let map = |style: &str| Style::from(style).current_map();
+        let patch = |prev: &str, next: &str| {
+            let (prev, next) = (map(prev), map(next));
+            style_patch(&prev, &next).map(|StylePatch { removed, changed }| {
+                (
+                    removed.into_iter().map(str::to_string).collect::<Vec<_>>(),
+                    changed
+                        .into_iter()
+                        .map(|(key, value)| format!("{key}: {value}"))
+                        .collect::<Vec<_>>(),
+                )
+            })
+        };
+
+        assert_eq!(
+            Some((vec!["padding".to_string()], vec!["color: blue".to_string()])),
+            patch("color: red; padding: 1rem;", "color: blue;")
+        );
+        assert_eq!(
+            Some((vec![], vec!["margin: 0".to_string()])),
+            patch("color: red;", "color: red; margin: 0;")
+        );
+
+        // Removing `margin` would also reset the unchanged `margin-top`.
+        assert_eq!(
+            None,
+            patch("margin: 0; margin-top: 4px;", "margin-top: 4px;")
+        );
+        assert_eq!(
+            None,
+            patch(
+                "margin: 0; margin-top: 4px;",
+                "margin: 1px; margin-top: 4px;"
+            )
+        );
+        assert_eq!(None, patch("inset: 0; top: 1px;", "top: 1px;"));
+        // Added properties are appended, so they cannot be inserted before existing ones.
+        assert_eq!(None, patch("color: red;", "margin: 0; color: red;"));
+        assert_eq!(
+            None,
+            patch("color: red; margin: 0;", "margin: 0; color: red;")
+        );
+        assert_eq!(
+            Some((vec![], vec!["--margin-top: 1px".to_string()])),
+            patch("--margin: 0;", "--margin: 0; --margin-top: 1px;")
+        );
+    }
+
+    #[test]
+    fn test_from_borrowed() {
+        let color = String::from("red");
+        assert_eq!(
+            Style::from("color: red; margin-top: 1px;"),
+            Style::from([("color", color.as_str()), ("marginTop", "1px")])
+        );
+
+        for style in [
+            Style::from([(
+                Cow::Borrowed("color"),
+                StyleValue::from(Cow::Borrowed("red")),
+            )]),
+            Style::from_static([("color", "red")]),
+        ] {
+            let style = style.sanitize(SanitizePolicy::Strip).unwrap();
+            let Some(InnerStyle::Structured(map)) = style.0 else {
+                panic!("style should be structured");
+            };
+            let (key, value) = map.first().unwrap();
+            assert!(matches!(key, Cow::Borrowed("color")));
+            assert!(matches!(
+                value.as_ref().map(|value| &value.value),
+                Some(Cow::Borrowed("red"))
+            ));
+        }
+        assert_eq!(
+            Style::from_static([("marginTop", "1px !important")]),
+            Style::from([("margin-top", "1px !important")])
+        );
+    }
+
+    #[test]
+    fn test_reactive() {
+        let owner = Owner::new();
+        owner.set();
+
+        let color = RwSignal::new("red");
+        let style = Style::reactive(move || [("color", color.get())]);
+        let with_defaults = style
+            .clone()
+            .with_defaults([("color", "black"), ("padding", "1rem")])
+            .with_property("margin", "0");
+        assert!(with_defaults.is_reactive());
+
+        assert_eq!("color: red;", style.to_string());
+        assert_eq!(
+            "color: red; padding: 1rem; margin: 0;",
+            with_defaults.to_string()
+        );
+        assert_eq!(
+            Some(StyleValue::new("red")),
+            style.get("color").as_deref().cloned()
+        );
+
+        color.set("blue");
+        assert_eq!(
+            Style::from([("color", "blue"), ("padding", "1rem"), ("margin", "0")]),
+            with_defaults.current()
+        );
+
+        let mut html = String::new();
+        Style::from("padding: 1rem;")
+            .merge(
+                Style::from(RwSignal::new(style.current())),
+                MergeStrategy::Combine,
+            )
+            .to_html(&mut html);
+        assert_eq!("padding: 1rem; color: blue;", html);
+
+        let mut style = with_defaults;
+        style.set("color", "green");
+        assert!(!style.is_reactive());
+        color.set("white");
+        assert_eq!("color: green; padding: 1rem; margin: 0;", style.to_string());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let json = r#"{"--accent":"blue","color":"var(--accent) !important","background-color":null,"margin":"1rem"}"#;
+        let style: Style = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            Style::from(IndexMap::from([
+                ("--accent".to_string(), Some(StyleValue::new("blue"))),
+                (
+                    "color".to_string(),
+                    Some(StyleValue::important("var(--accent)"))
+                ),
+                ("background-color".to_string(), None),
+                ("margin".to_string(), Some(StyleValue::new("1rem"))),
+            ])),
+            style
+        );
+        assert_eq!(json, serde_json::to_string(&style).unwrap());
+
+        for style in [
+            Style::default(),
+            Style::from("color: red; margin: 1rem;"),
+            Style::from([("margin", Some("1rem")), ("padding", None)]),
+        ] {
+            let json = serde_json::to_string(&style).unwrap();
+            assert_eq!(style, serde_json::from_str(&json).unwrap(), "{json}");
+        }
+
+        assert_eq!(
+            "\"color: red;\"",
+            serde_json::to_string(&Style::from("color: red;")).unwrap()
+        );
+        assert_eq!("null", serde_json::to_string(&Style::default()).unwrap());
+    }
 }
@@ -0,0 +1,395 @@
+use leptos::{
+    reactive::effect::RenderEffect,
+    tachys::{
+        html::{
+            attribute::{Attribute, AttributeValue, NextAttribute},
+            style::IntoStyle,
+        },
+        renderer::{types::Element, Rndr},
+    },
+};
+
+use crate::{custom_property::is_custom_property, style::Style};
+
+/// CSS properties that are also SVG presentation attributes, sorted.
+///
+/// `transform` and `d` are excluded, because their attribute syntax differs from CSS.
+const PRESENTATION_ATTRIBUTES: [&str; 63] = [
+    "alignment-baseline",
+    "baseline-shift",
+    "clip",
+    "clip-path",
+    "clip-rule",
+    "color",
+    "color-interpolation",
+    "color-interpolation-filters",
+    "color-rendering",
+    "cursor",
+    "cx",
+    "cy",
+    "direction",
+    "display",
+    "dominant-baseline",
+    "fill",
+    "fill-opacity",
+    "fill-rule",
+    "filter",
+    "flood-color",
+    "flood-opacity",
+    "font-family",
+    "font-size",
+    "font-size-adjust",
+    "font-stretch",
+    "font-style",
+    "font-variant",
+    "font-weight",
+    "image-rendering",
+    "letter-spacing",
+    "lighting-color",
+    "marker-end",
+    "marker-mid",
+    "marker-start",
+    "mask",
+    "opacity",
+    "overflow",
+    "paint-order",
+    "pointer-events",
+    "r",
+    "rx",
+    "ry",
+    "shape-rendering",
+    "stop-color",
+    "stop-opacity",
+    "stroke",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-miterlimit",
+    "stroke-opacity",
+    "stroke-width",
+    "text-anchor",
+    "text-decoration",
+    "text-rendering",
+    "unicode-bidi",
+    "vector-effect",
+    "visibility",
+    "word-spacing",
+    "writing-mode",
+    "x",
+    "y",
+];
+
+/// CSS functions that presentation attributes do not support in viewers without CSS.
+const CSS_FUNCTIONS: [&str; 7] = ["var(", "env(", "attr(", "calc(", "min(", "max(", "clamp("];
+
+/// Returns whether a property is also an SVG presentation attribute (e.g. `fill` or `stroke-width`).
+pub fn is_presentation_attribute(property: &str) -> bool {
+    PRESENTATION_ATTRIBUTES.binary_search(&property).is_ok()
+}
+
+/// SVG presentation attributes and the remaining declarations of a style.
+pub type PresentationAttributes = (Vec<(String, String)>, Style);
+
+impl Style {
+    /// Splits the declarations into SVG presentation attributes and the remaining style.
+    ///
+    /// A declaration becomes an attribute if its property is a presentation attribute, it is not `!important` and
+    /// its value does not use CSS functions like `var()` or `calc()`. Other declarations are kept in the style.
+    /// Reactive styles are split by their current value.
+    pub fn into_presentation_attributes(self) -> PresentationAttributes {
+        let mut attributes = vec![];
+        let mut style = self.current();
+
+        style.retain(|property, value| {
+            let presentable = !is_custom_property(property)
+                && is_presentation_attribute(property)
+                && !value.important
+                && !CSS_FUNCTIONS
+                    .iter()
+                    .any(|function| value.to_ascii_lowercase().contains(function));
+
+            if presentable {
+                attributes.push((property.to_string(), value.to_string()));
+            }
+            !presentable
+        });
+
+        (attributes, style)
+    }
+}
+
+/// An [`Attribute`] rendering a [`Style`] on an SVG element, as presentation attributes where possible.
+///
+/// See [`Style::into_presentation_attributes`]. The struct component derive uses it for `style` fields of SVG tags
+/// with `#[struct_component(presentation_attributes)]`. Presentation attributes have a lower priority than any author
+/// style sheet rule, unlike inline styles.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SvgStyle(pub Style);
+
+impl From<Style> for SvgStyle {
+    fn from(value: Style) -> SvgStyle {
+        SvgStyle(value)
+    }
+}
+
+impl SvgStyle {
+    /// Resolves and sanitizes the style as when rendering, and splits it.
+    fn split(self) -> (SvgAttributes, Style) {
+        let style = self.0.prepare_for_render();
+
+        if style.is_reactive() {
+            let remaining = style
+                .clone()
+                .map_static(|style| style.into_presentation_attributes().1);
+            (SvgAttributes::Reactive(style), remaining)
+        } else {
+            let (attributes, remaining) = style.into_presentation_attributes();
+            (SvgAttributes::Static(attributes), remaining)
+        }
+    }
+}
+
+enum SvgAttributes {
+    Static(Vec<(String, String)>),
+    Reactive(Style),
+}
+
+/// The rendering state of an [`SvgStyle`].
+pub struct SvgStyleState {
+    el: Element,
+    attributes: RenderedAttributes,
+    style: <Style as IntoStyle>::State,
+}
+
+enum RenderedAttributes {
+    Static(Vec<(String, String)>),
+    Reactive(RenderEffect<Vec<(String, String)>>),
+}
+
+/// Sets changed attributes and removes attributes that are no longer present.
+fn update_attributes(el: &Element, prev: &[(String, String)], attributes: &[(String, String)]) {
+    for (key, _) in prev {
+        if !attributes.iter().any(|(other, _)| other == key) {
+            Rndr::remove_attribute(el, key);
+        }
+    }
+    for attribute in attributes {
+        if !prev.contains(attribute) {
+            Rndr::set_attribute(el, &attribute.0, &attribute.1);
+        }
+    }
+}
+
+/// Renders the presentation attributes of each value of a reactive style, updating the previous value.
+fn attributes_effect(
+    el: &Element,
+    style: Style,
+    prev: Option<Vec<(String, String)>>,
+    set_first: bool,
+) -> RenderEffect<Vec<(String, String)>> {
+    let el = el.clone();
+
+    RenderEffect::new_with_value(
+        move |prev| {
+            let (attributes, _) = style.current().into_presentation_attributes();
+            match prev {
+                Some(prev) => update_attributes(&el, &prev, &attributes),
+                None if set_first => update_attributes(&el, &[], &attributes),
+                None => {}
+            }
+            attributes
+        },
+        prev,
+    )
+}
+
+impl Attribute for SvgStyle {
+    const MIN_LENGTH: usize = 0;
+
+    type AsyncOutput = Self;
+    type State = SvgStyleState;
+    type Cloneable = Self;
+    type CloneableOwned = Self;
+
+    fn html_len(&self) -> usize {
+        0
+    }
+
+    fn to_html(
+        self,
+        buf: &mut String,
+        _class: &mut String,
+        style: &mut String,
+        _inner_html: &mut String,
+    ) {
+        let (attributes, remaining) = self.split();
+        let attributes = match attributes {
+            SvgAttributes::Static(attributes) => attributes,
+            SvgAttributes::Reactive(style) => style.into_presentation_attributes().0,
+        };
+
+        for (key, value) in attributes {
+            AttributeValue::to_html(value, &key, buf);
+        }
+        IntoStyle::to_html(remaining, style);
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(self, el: &Element) -> Self::State {
+        let (attributes, remaining) = self.split();
+
+        SvgStyleState {
+            el: el.clone(),
+            attributes: match attributes {
+                SvgAttributes::Static(attributes) => {
+                    if !FROM_SERVER {
+                        update_attributes(el, &[], &attributes);
+                    }
+                    RenderedAttributes::Static(attributes)
+                }
+                SvgAttributes::Reactive(style) => {
+                    RenderedAttributes::Reactive(attributes_effect(el, style, None, !FROM_SERVER))
+                }
+            },
+            style: remaining.hydrate::<FROM_SERVER>(el),
+        }
+    }
+
+    fn build(self, el: &Element) -> Self::State {
+        let (attributes, remaining) = self.split();
+
+        SvgStyleState {
+            el: el.clone(),
+            attributes: match attributes {
+                SvgAttributes::Static(attributes) => {
+                    update_attributes(el, &[], &attributes);
+                    RenderedAttributes::Static(attributes)
+                }
+                SvgAttributes::Reactive(style) => {
+                    RenderedAttributes::Reactive(attributes_effect(el, style, None, true))
+                }
+            },
+            style: remaining.build(el),
+        }
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        let (attributes, remaining) = self.split();
+        let SvgStyleState {
+            el,
+            attributes: rendered,
+            style,
+        } = state;
+
+        let prev = match rendered {
+            RenderedAttributes::Static(prev) => Some(std::mem::take(prev)),
+            RenderedAttributes::Reactive(effect) => effect.take_value(),
+        };
+        *rendered = match attributes {
+            SvgAttributes::Static(attributes) => {
+                update_attributes(el, &prev.unwrap_or_default(), &attributes);
+                RenderedAttributes::Static(attributes)
+            }
+            SvgAttributes::Reactive(style) => {
+                RenderedAttributes::Reactive(attributes_effect(el, style, prev, true))
+            }
+        };
+
+        remaining.rebuild(style);
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        self
+    }
+
+    fn into_cloneable_owned(self) -> Self::CloneableOwned {
+        self
+    }
+
+    fn dry_resolve(&mut self) {
+        self.0.dry_resolve();
+    }
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self
+    }
+}
+
+impl NextAttribute for SvgStyle {
+    type Output<NewAttr: Attribute> = (Self, NewAttr);
+
+    fn add_any_attr<NewAttr: Attribute>(self, new_attr: NewAttr) -> Self::Output<NewAttr> {
+        (self, new_attr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos::prelude::{Get, Owner, RwSignal, Set};
+
+    use super::*;
+    use crate::value::StyleValue;
+
+    #[test]
+    fn test_presentation_attributes() {
+        assert!(PRESENTATION_ATTRIBUTES
+            .windows(2)
+            .all(|pair| pair[0] < pair[1]));
+
+        assert_eq!(
+            (
+                vec![
+                    ("fill".to_string(), "red".to_string()),
+                    ("stroke-width".to_string(), "2".to_string()),
+                ],
+                Style::from([
+                    ("stroke", StyleValue::important("blue")),
+                    ("opacity", StyleValue::new("var(--opacity)")),
+                    ("transform", StyleValue::new("rotate(45deg)")),
+                    ("--size", StyleValue::new("1px")),
+                ]),
+            ),
+            Style::from(
+                "fill: red; stroke: blue !important; stroke-width: 2; opacity: var(--opacity); \
+                 transform: rotate(45deg); --size: 1px;"
+            )
+            .into_presentation_attributes()
+        );
+    }
+
+    #[test]
+    fn test_to_html() {
+        let owner = Owner::new();
+        owner.set();
+
+        let render = |style: Style| {
+            let (mut buf, mut class, mut style_buf, mut inner_html) =
+                (String::new(), String::new(), String::new(), String::new());
+            SvgStyle::from(style).to_html(&mut buf, &mut class, &mut style_buf, &mut inner_html);
+            (buf, style_buf)
+        };
+
+        assert_eq!(
+            (
+                " fill=\"red\" stroke-width=\"2\"".to_string(),
+                "cursor: pointer !important;".to_string()
+            ),
+            render(Style::from(
+                "fill: red; cursor: pointer !important; stroke-width: 2;"
+            ))
+        );
+
+        let fill = RwSignal::new("red");
+        let style = Style::reactive(move || [("fill", fill.get()), ("margin", "0")]);
+        assert_eq!(
+            (" fill=\"red\"".to_string(), "margin: 0;".to_string()),
+            render(style.clone())
+        );
+
+        fill.set("var(--fill)");
+        assert_eq!(
+            (String::new(), "fill: var(--fill); margin: 0;".to_string()),
+            render(style)
+        );
+    }
+}
@@ -0,0 +1,743 @@
+use std::collections::HashSet;
+
+/// Default Tailwind color palettes, used with a shade (e.g. `red-500`).
+const PALETTES: [&str; 22] = [
+    "slate", "gray", "zinc", "neutral", "stone", "red", "orange", "amber", "yellow", "lime",
+    "green", "emerald", "teal", "cyan", "sky", "blue", "indigo", "violet", "purple", "fuchsia",
+    "pink", "rose",
+];
+
+/// Default Tailwind colors without a shade.
+const COLORS: [&str; 5] = ["inherit", "current", "transparent", "black", "white"];
+
+const FONT_SIZES: [&str; 13] = [
+    "xs", "sm", "base", "lg", "xl", "2xl", "3xl", "4xl", "5xl", "6xl", "7xl", "8xl", "9xl",
+];
+
+const FONT_WEIGHTS: [&str; 9] = [
+    "thin",
+    "extralight",
+    "light",
+    "normal",
+    "medium",
+    "semibold",
+    "bold",
+    "extrabold",
+    "black",
+];
+
+const FONT_FAMILIES: [&str; 3] = ["sans", "serif", "mono"];
+
+const SHADOWS: [&str; 8] = ["", "sm", "md", "lg", "xl", "2xl", "inner", "none"];
+
+/// Classes without a value and their utility group.
+const STANDALONE_CLASSES: [(&str, &str); 49] = [
+    ("block", "display"),
+    ("inline-block", "display"),
+    ("inline", "display"),
+    ("flex", "display"),
+    ("inline-flex", "display"),
+    ("grid", "display"),
+    ("inline-grid", "display"),
+    ("table", "display"),
+    ("inline-table", "display"),
+    ("table-row", "display"),
+    ("table-cell", "display"),
+    ("contents", "display"),
+    ("flow-root", "display"),
+    ("list-item", "display"),
+    ("hidden", "display"),
+    ("static", "position"),
+    ("fixed", "position"),
+    ("absolute", "position"),
+    ("relative", "position"),
+    ("sticky", "position"),
+    ("visible", "visibility"),
+    ("invisible", "visibility"),
+    ("collapse", "visibility"),
+    ("italic", "font-style"),
+    ("not-italic", "font-style"),
+    ("underline", "text-decoration"),
+    ("overline", "text-decoration"),
+    ("line-through", "text-decoration"),
+    ("no-underline", "text-decoration"),
+    ("uppercase", "text-transform"),
+    ("lowercase", "text-transform"),
+    ("capitalize", "text-transform"),
+    ("normal-case", "text-transform"),
+    ("truncate", "text-overflow"),
+    ("antialiased", "font-smoothing"),
+    ("subpixel-antialiased", "font-smoothing"),
+    ("sr-only", "sr"),
+    ("not-sr-only", "sr"),
+    ("isolate", "isolation"),
+    ("isolation-auto", "isolation"),
+    ("grow", "flex-grow"),
+    ("shrink", "flex-shrink"),
+    ("border", "border-width"),
+    ("rounded", "rounded"),
+    ("shadow", "shadow"),
+    ("ring", "ring-width"),
+    ("outline", "outline-style"),
+    ("transition", "transition"),
+    ("container", "container"),
+];
+
+/// Utility groups and the groups they override.
+const CONFLICTING_GROUPS: [(&str, &[&str]); 19] = [
+    ("p", &["px", "py", "ps", "pe", "pt", "pr", "pb", "pl"]),
+    ("px", &["pr", "pl"]),
+    ("py", &["pt", "pb"]),
+    ("m", &["mx", "my", "ms", "me", "mt", "mr", "mb", "ml"]),
+    ("mx", &["mr", "ml"]),
+    ("my", &["mt", "mb"]),
+    ("size", &["w", "h"]),
+    (
+        "inset",
+        &[
+            "inset-x", "inset-y", "start", "end", "top", "right", "bottom", "left",
+        ],
+    ),
+    ("inset-x", &["right", "left"]),
+    ("inset-y", &["top", "bottom"]),
+    ("gap", &["gap-x", "gap-y"]),
+    ("overflow", &["overflow-x", "overflow-y"]),
+    ("overscroll", &["overscroll-x", "overscroll-y"]),
+    (
+        "rounded",
+        &[
+            "rounded-s",
+            "rounded-e",
+            "rounded-t",
+            "rounded-r",
+            "rounded-b",
+            "rounded-l",
+            "rounded-ss",
+            "rounded-se",
+            "rounded-ee",
+            "rounded-es",
+            "rounded-tl",
+            "rounded-tr",
+            "rounded-br",
+            "rounded-bl",
+        ],
+    ),
+    (
+        "border-width",
+        &[
+            "border-width-x",
+            "border-width-y",
+            "border-width-s",
+            "border-width-e",
+            "border-width-t",
+            "border-width-r",
+            "border-width-b",
+            "border-width-l",
+        ],
+    ),
+    ("border-width-x", &["border-width-r", "border-width-l"]),
+    ("border-width-y", &["border-width-t", "border-width-b"]),
+    (
+        "border-color",
+        &[
+            "border-color-x",
+            "border-color-y",
+            "border-color-s",
+            "border-color-e",
+            "border-color-t",
+            "border-color-r",
+            "border-color-b",
+            "border-color-l",
+        ],
+    ),
+    ("font-size", &["leading"]),
+];
+
+/// Tailwind theme configuration for class conflict resolution.
+///
+/// The default configuration matches the default Tailwind theme. Custom theme scales are added with the `with_*`
+/// methods. Provide a configuration as context to use it in [`Class::with_defaults`](crate::Class::with_defaults).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TailwindConfig {
+    /// Custom color names (e.g. `primary` for `bg-primary` and `bg-primary-500`).
+    pub colors: Vec<String>,
+    /// Custom font sizes (e.g. `huge` for `text-huge`).
+    pub font_sizes: Vec<String>,
+    /// Custom font families (e.g. `display` for `font-display`).
+    pub font_families: Vec<String>,
+    /// Custom box shadows (e.g. `card` for `shadow-card`).
+    pub shadows: Vec<String>,
+}
+
+impl TailwindConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_colors<I: IntoIterator<Item = S>, S: Into<String>>(mut self, colors: I) -> Self {
+        self.colors.extend(colors.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn with_font_sizes<I: IntoIterator<Item = S>, S: Into<String>>(
+        mut self,
+        font_sizes: I,
+    ) -> Self {
+        self.font_sizes
+            .extend(font_sizes.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn with_font_families<I: IntoIterator<Item = S>, S: Into<String>>(
+        mut self,
+        font_families: I,
+    ) -> Self {
+        self.font_families
+            .extend(font_families.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn with_shadows<I: IntoIterator<Item = S>, S: Into<String>>(mut self, shadows: I) -> Self {
+        self.shadows.extend(shadows.into_iter().map(Into::into));
+        self
+    }
+
+    /// Removes classes that are overridden by a later class of the same utility group, with the same variants.
+    ///
+    /// Classes that are not recognized as Tailwind utilities are kept.
+    pub fn merge<'a, I: IntoIterator<Item = &'a str>>(&self, classes: I) -> Vec<&'a str> {
+        let classes = classes.into_iter().collect::<Vec<_>>();
+        let mut overridden = HashSet::new();
+        let mut merged = vec![];
+
+        for class in classes.into_iter().rev() {
+            let Some((prefix, group)) = self.conflict_key(class) else {
+                merged.push(class);
+                continue;
+            };
+
+            if !overridden.insert(format!("{prefix}{group}")) {
+                continue;
+            }
+
+            for conflicting_group in conflicting_groups(group) {
+                overridden.insert(format!("{prefix}{conflicting_group}"));
+            }
+            merged.push(class);
+        }
+
+        merged.reverse();
+        merged
+    }
+
+    /// Returns the normalized variants and important modifier, and the utility group of a class.
+    fn conflict_key<'a>(&self, class: &'a str) -> Option<(String, &'a str)> {
+        let (variants, base) = split_variants(class);
+
+        let (base, important) = if let Some(base) = base.strip_prefix('!') {
+            (base, true)
+        } else if let Some(base) = base.strip_suffix('!') {
+            (base, true)
+        } else {
+            (base, false)
+        };
+        let base = base.strip_prefix('-').unwrap_or(base);
+
+        let group = self.group(base)?;
+
+        let mut prefix = sort_variants(variants).join(":");
+        prefix.push(':');
+        if important {
+            prefix.push('!');
+        }
+
+        Some((prefix, group))
+    }
+
+    /// Returns the utility group of a class without variants.
+    fn group<'a>(&self, base: &'a str) -> Option<&'a str> {
+        if let Some((_, group)) = STANDALONE_CLASSES.iter().find(|(class, _)| *class == base) {
+            return Some(group);
+        }
+
+        // Arbitrary properties, e.g. `[mask-type:luminance]`.
+        if base.starts_with('[') && base.ends_with(']') {
+            return base.find(':').map(|index| &base[..=index]);
+        }
+
+        // Try the longest prefix first, e.g. `border-x` before `border` for `border-x-2`.
+        let end = base.find('[').unwrap_or(base.len());
+        let dashes = base[..end]
+            .match_indices('-')
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
+        dashes.into_iter().rev().find_map(|index| {
+            let (prefix, value) = (&base[..index], &base[index + 1..]);
+            self.prefix_group(prefix, value)
+        })
+    }
+
+    /// Returns the utility group for a prefix and value, which is the prefix itself for most utilities.
+    fn prefix_group<'a>(&self, prefix: &'a str, value: &str) -> Option<&'a str> {
+        Some(match prefix {
+            "p" | "px" | "py" | "ps" | "pe" | "pt" | "pr" | "pb" | "pl" | "m" | "mx" | "my"
+            | "ms" | "me" | "mt" | "mr" | "mb" | "ml" | "w" | "h" | "size" | "min-w" | "min-h"
+            | "max-w" | "max-h" | "inset" | "inset-x" | "inset-y" | "start" | "end" | "top"
+            | "right" | "bottom" | "left" | "gap" | "gap-x" | "gap-y" | "space-x" | "space-y"
+            | "z" | "opacity" | "leading" | "tracking" | "order" | "basis" | "grid-cols"
+            | "grid-rows" | "col" | "col-span" | "col-start" | "col-end" | "row" | "row-span"
+            | "row-start" | "row-end" | "items" | "justify" | "justify-items" | "justify-self"
+            | "content" | "self" | "place-content" | "place-items" | "place-self" | "overflow"
+            | "overflow-x" | "overflow-y" | "overscroll" | "overscroll-x" | "overscroll-y"
+            | "cursor" | "select" | "pointer-events" | "duration" | "delay" | "ease"
+            | "animate" | "rotate" | "scale" | "scale-x" | "scale-y" | "translate-x"
+            | "translate-y" | "skew-x" | "skew-y" | "origin" | "aspect" | "columns"
+            | "line-clamp" | "object" | "whitespace" | "break" | "list" | "accent" | "caret"
+            | "blur" | "brightness" | "contrast" | "grayscale" | "invert" | "saturate"
+            | "sepia" | "backdrop-blur" | "indent" | "align" | "mix-blend" | "box" | "float"
+            | "clear" | "auto-cols" | "auto-rows" | "grid-flow" | "will-change" | "appearance"
+            | "touch" | "resize" | "snap" | "scroll-m" | "scroll-p" | "fill" | "outline-offset"
+            | "from" | "via" | "to" => prefix,
+            "flex" => match value {
+                "row" | "row-reverse" | "col" | "col-reverse" => "flex-direction",
+                "wrap" | "wrap-reverse" | "nowrap" => "flex-wrap",
+                _ => "flex",
+            },
+            "grow" => "flex-grow",
+            "shrink" => "flex-shrink",
+            "rounded" | "rounded-s" | "rounded-e" | "rounded-t" | "rounded-r" | "rounded-b"
+            | "rounded-l" | "rounded-ss" | "rounded-se" | "rounded-ee" | "rounded-es"
+            | "rounded-tl" | "rounded-tr" | "rounded-br" | "rounded-bl" => prefix,
+            "text" => match value {
+                "left" | "center" | "right" | "justify" | "start" | "end" => "text-align",
+                "wrap" | "nowrap" | "balance" | "pretty" => "text-wrap",
+                "ellipsis" | "clip" => "text-overflow",
+                value if self.is_font_size(value) => "font-size",
+                value if self.is_color(value) => "text-color",
+                _ => return None,
+            },
+            "font" => match value {
+                value if FONT_WEIGHTS.contains(&value) || is_arbitrary_number(value) => {
+                    "font-weight"
+                }
+                value
+                    if FONT_FAMILIES.contains(&value)
+                        || self.font_families.iter().any(|family| family == value)
+                        || is_arbitrary(value) =>
+                {
+                    "font-family"
+                }
+                _ => return None,
+            },
+            "bg" => match value {
+                "fixed" | "local" | "scroll" => "bg-attachment",
+                "auto" | "cover" | "contain" => "bg-size",
+                "repeat" | "no-repeat" | "repeat-x" | "repeat-y" | "repeat-round"
+                | "repeat-space" => "bg-repeat",
+                "bottom" | "center" | "left" | "left-bottom" | "left-top" | "right"
+                | "right-bottom" | "right-top" | "top" => "bg-position",
+                "none" => "bg-image",
+                value if value.starts_with("gradient-to-") => "bg-image",
+                value if value.starts_with("clip-") => "bg-clip",
+                value if value.starts_with("origin-") => "bg-origin",
+                value if is_arbitrary_with(value, &["url:", "image:", "url("]) => "bg-image",
+                value if is_arbitrary_with(value, &["length:", "size:"]) => "bg-size",
+                value if is_arbitrary_with(value, &["position:"]) => "bg-position",
+                value if self.is_color(value) => "bg-color",
+                _ => return None,
+            },
+            "border" | "border-x" | "border-y" | "border-s" | "border-e" | "border-t"
+            | "border-r" | "border-b" | "border-l" => {
+                let side = prefix.strip_prefix("border").unwrap_or_default();
+
+                match value {
+                    "solid" | "dashed" | "dotted" | "double" | "hidden" | "none"
+                        if side.is_empty() =>
+                    {
+                        "border-style"
+                    }
+                    "collapse" | "separate" if side.is_empty() => "border-collapse",
+                    value if value.is_empty() || is_number(value) || is_arbitrary_length(value) => {
+                        border_group("border-width", side)
+                    }
+                    value if self.is_color(value) => border_group("border-color", side),
+                    _ => return None,
+                }
+            }
+            "ring" => match value {
+                "inset" => "ring-inset",
+                value if value.is_empty() || is_number(value) || is_arbitrary_length(value) => {
+                    "ring-width"
+                }
+                value if self.is_color(value) => "ring-color",
+                _ => return None,
+            },
+            "ring-offset" => match value {
+                value if is_number(value) || is_arbitrary_length(value) => "ring-offset-width",
+                value if self.is_color(value) => "ring-offset-color",
+                _ => return None,
+            },
+            "outline" => match value {
+                "none" | "dashed" | "dotted" | "double" => "outline-style",
+                value if is_number(value) || is_arbitrary_length(value) => "outline-width",
+                value if self.is_color(value) => "outline-color",
+                _ => return None,
+            },
+            "shadow" => match value {
+                value
+                    if SHADOWS.contains(&value)
+                        || self.shadows.iter().any(|shadow| shadow == value)
+                        || is_arbitrary_with(value, &["shadow:"])
+                        || is_arbitrary_length(value) =>
+                {
+                    "shadow"
+                }
+                value if self.is_color(value) => "shadow-color",
+                _ => return None,
+            },
+            "decoration" => match value {
+                "solid" | "double" | "dotted" | "dashed" | "wavy" => "decoration-style",
+                value if is_number(value) || is_arbitrary_length(value) || value == "auto" => {
+                    "decoration-thickness"
+                }
+                value if self.is_color(value) => "decoration-color",
+                _ => return None,
+            },
+            "stroke" => match value {
+                value if is_number(value) || is_arbitrary_length(value) => "stroke-w",
+                value if self.is_color(value) => "stroke",
+                _ => return None,
+            },
+            "transition" => "transition",
+            _ => return None,
+        })
+    }
+
+    fn is_font_size(&self, value: &str) -> bool {
+        // Font sizes can have a line height modifier, e.g. `text-lg/7`.
+        let value = value
+            .split_once('/')
+            .filter(|_| !is_arbitrary(value))
+            .map_or(value, |(value, _)| value);
+
+        FONT_SIZES.contains(&value)
+            || self.font_sizes.iter().any(|font_size| font_size == value)
+            || is_arbitrary_length(value)
+    }
+
+    fn is_color(&self, value: &str) -> bool {
+        if is_arbitrary(value) {
+            return !is_arbitrary_length(value);
+        }
+
+        // Colors can have an opacity modifier, e.g. `bg-black/50`.
+        let value = value.split_once('/').map_or(value, |(value, _)| value);
+
+        COLORS.contains(&value)
+            || value
+                .split_once('-')
+                .is_some_and(|(palette, shade)| PALETTES.contains(&palette) && is_number(shade))
+            || self.colors.iter().any(|color| {
+                value == color
+                    || value
+                        .strip_prefix(color.as_str())
+                        .is_some_and(|shade| shade.starts_with('-'))
+            })
+    }
+}
+
+/// Returns the groups overridden by `group`.
+fn conflicting_groups(group: &str) -> &'static [&'static str] {
+    CONFLICTING_GROUPS
+        .iter()
+        .find(|(conflicting_group, _)| *conflicting_group == group)
+        .map(|(_, groups)| *groups)
+        .unwrap_or_default()
+}
+
+fn border_group(group: &'static str, side: &str) -> &'static str {
+    match (group, side) {
+        ("border-width", "") => "border-width",
+        ("border-width", "-x") => "border-width-x",
+        ("border-width", "-y") => "border-width-y",
+        ("border-width", "-s") => "border-width-s",
+        ("border-width", "-e") => "border-width-e",
+        ("border-width", "-t") => "border-width-t",
+        ("border-width", "-r") => "border-width-r",
+        ("border-width", "-b") => "border-width-b",
+        ("border-width", "-l") => "border-width-l",
+        ("border-color", "-x") => "border-color-x",
+        ("border-color", "-y") => "border-color-y",
+        ("border-color", "-s") => "border-color-s",
+        ("border-color", "-e") => "border-color-e",
+        ("border-color", "-t") => "border-color-t",
+        ("border-color", "-r") => "border-color-r",
+        ("border-color", "-b") => "border-color-b",
+        ("border-color", "-l") => "border-color-l",
+        _ => "border-color",
+    }
+}
+
+/// Splits a class into its variants (e.g. `hover` and `md`) and the utility, ignoring colons in brackets.
+fn split_variants(class: &str) -> (Vec<&str>, &str) {
+    let mut variants = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (index, char) in class.char_indices() {
+        match char {
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth = depth.saturating_sub(1),
+            ':' if depth == 0 => {
+                variants.push(&class[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    (variants, &class[start..])
+}
+
+/// Sorts variants, keeping the position of arbitrary variants (e.g. `[&>*]`), since their order matters.
+fn sort_variants(variants: Vec<&str>) -> Vec<&str> {
+    let mut sorted = vec![];
+    let mut unsorted = vec![];
+
+    for variant in variants {
+        if variant.starts_with('[') {
+            unsorted.sort_unstable();
+            sorted.append(&mut unsorted);
+            sorted.push(variant);
+        } else {
+            unsorted.push(variant);
+        }
+    }
+    unsorted.sort_unstable();
+    sorted.append(&mut unsorted);
+
+    sorted
+}
+
+fn is_number(value: &str) -> bool {
+    !value.is_empty() && value.parse::<f64>().is_ok()
+}
+
+fn is_arbitrary(value: &str) -> bool {
+    value.starts_with('[') && value.ends_with(']')
+}
+
+fn is_arbitrary_number(value: &str) -> bool {
+    is_arbitrary(value) && is_number(&value[1..value.len() - 1])
+}
+
+fn is_arbitrary_with(value: &str, prefixes: &[&str]) -> bool {
+    is_arbitrary(value) && prefixes.iter().any(|prefix| value[1..].starts_with(prefix))
+}
+
+fn is_arbitrary_length(value: &str) -> bool {
+    if !is_arbitrary(value) {
+        return false;
+    }
+
+    let inner = &value[1..value.len() - 1];
+    if let Some(inner) = inner.strip_prefix("length:") {
+        return !inner.is_empty();
+    }
+
+    inner.starts_with(|char: char| char.is_ascii_digit() || char == '.')
+        && inner
+            .trim_start_matches(|char: char| char.is_ascii_digit() || char == '.')
+            .chars()
+            .all(|char| char.is_ascii_alphabetic() || char == '%')
+        || ["calc(", "min(", "max(", "clamp("]
+            .iter()
+            .any(|function| inner.starts_with(function))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn merge(config: &TailwindConfig, classes: &str) -> String {
+        config.merge(classes.split_whitespace()).join(" ")
+    }
+
+    #[test]
+    fn test_merge() {
+        let config = TailwindConfig::default();
+
+        for (input, expected) in [
+            // Unknown classes are kept.
+            ("button button-primary", "button button-primary"),
+            ("px-4 foo px-2", "foo px-2"),
+            // Spacing.
+            ("px-4 px-2", "px-2"),
+            ("p-4 px-2", "p-4 px-2"),
+            ("px-2 p-4", "p-4"),
+            ("py-2 pt-4", "py-2 pt-4"),
+            ("pt-4 py-2", "py-2"),
+            ("m-2 -m-4", "-m-4"),
+            ("mx-auto ml-2", "mx-auto ml-2"),
+            ("ml-2 mx-auto", "mx-auto"),
+            ("gap-x-2 gap-4", "gap-4"),
+            ("space-x-2 space-y-2 space-x-4", "space-y-2 space-x-4"),
+            ("p-[3px] p-2", "p-2"),
+            // Sizing.
+            ("w-4 w-1/2", "w-1/2"),
+            ("w-4 h-4 size-8", "size-8"),
+            ("size-8 w-4", "size-8 w-4"),
+            ("min-w-0 max-w-screen-xl max-w-md", "min-w-0 max-w-md"),
+            ("w-full h-[calc(100%-1rem)] h-screen", "w-full h-screen"),
+            // Inset.
+            ("top-0 left-0 inset-2", "inset-2"),
+            ("inset-x-0 right-2", "inset-x-0 right-2"),
+            ("right-2 inset-x-0", "inset-x-0"),
+            // Typography.
+            ("text-sm text-lg", "text-lg"),
+            ("text-sm text-red-500", "text-sm text-red-500"),
+            ("text-red-500 text-blue-500/50", "text-blue-500/50"),
+            ("text-white text-[#fff]", "text-[#fff]"),
+            ("text-[12px] text-base", "text-base"),
+            ("text-lg text-lg/7", "text-lg/7"),
+            ("leading-9 text-lg", "text-lg"),
+            ("text-lg leading-9", "text-lg leading-9"),
+            ("text-left text-center text-lg", "text-center text-lg"),
+            ("font-bold font-medium", "font-medium"),
+            ("font-bold font-mono", "font-bold font-mono"),
+            ("font-sans font-[Inter]", "font-[Inter]"),
+            ("font-bold font-[550]", "font-[550]"),
+            ("italic not-italic", "not-italic"),
+            ("underline no-underline", "no-underline"),
+            ("uppercase capitalize", "capitalize"),
+            ("tracking-wide tracking-tight", "tracking-tight"),
+            // Colors.
+            ("bg-red-500 bg-blue-500", "bg-blue-500"),
+            ("bg-black/50 bg-transparent", "bg-transparent"),
+            ("bg-red-500 bg-cover", "bg-red-500 bg-cover"),
+            ("bg-[url(/a.png)] bg-none", "bg-none"),
+            ("bg-[url(/a.png)] bg-red-500", "bg-[url(/a.png)] bg-red-500"),
+            ("bg-[#fff] bg-white", "bg-white"),
+            ("fill-current fill-none", "fill-none"),
+            ("stroke-red-500 stroke-2", "stroke-red-500 stroke-2"),
+            ("accent-red-500 accent-auto", "accent-auto"),
+            // Borders.
+            ("border border-2", "border-2"),
+            ("border-2 border-red-500", "border-2 border-red-500"),
+            ("border-red-500 border-blue-500", "border-blue-500"),
+            ("border-t-2 border-4", "border-4"),
+            ("border-4 border-t-2", "border-4 border-t-2"),
+            ("border-x-2 border-l-4", "border-x-2 border-l-4"),
+            ("border-l-4 border-x-2", "border-x-2"),
+            ("border-t-red-500 border-blue-500", "border-blue-500"),
+            ("border-solid border-dashed", "border-dashed"),
+            ("border-[3px] border-[#000]", "border-[3px] border-[#000]"),
+            ("rounded-tl-lg rounded-lg", "rounded-lg"),
+            ("rounded rounded-none", "rounded-none"),
+            ("rounded-lg rounded-t-none", "rounded-lg rounded-t-none"),
+            ("ring ring-2 ring-blue-500", "ring-2 ring-blue-500"),
+            (
+                "ring-offset-2 ring-offset-white",
+                "ring-offset-2 ring-offset-white",
+            ),
+            ("outline outline-none", "outline-none"),
+            (
+                "outline-2 outline-red-500 outline-offset-2",
+                "outline-2 outline-red-500 outline-offset-2",
+            ),
+            ("shadow shadow-lg", "shadow-lg"),
+            ("shadow-lg shadow-red-500", "shadow-lg shadow-red-500"),
+            (
+                "decoration-2 decoration-wavy decoration-red-500",
+                "decoration-2 decoration-wavy decoration-red-500",
+            ),
+            // Layout.
+            ("block flex", "flex"),
+            ("hidden md:block", "hidden md:block"),
+            ("absolute relative", "relative"),
+            ("flex-row flex-col", "flex-col"),
+            ("flex-row flex-wrap flex-1", "flex-row flex-wrap flex-1"),
+            ("flex-1 flex-auto", "flex-auto"),
+            ("grow-0 grow", "grow"),
+            ("items-center items-start", "items-start"),
+            ("justify-between justify-center", "justify-center"),
+            ("overflow-x-auto overflow-hidden", "overflow-hidden"),
+            (
+                "overflow-hidden overflow-x-auto",
+                "overflow-hidden overflow-x-auto",
+            ),
+            ("z-10 z-[100]", "z-[100]"),
+            ("opacity-50 opacity-100", "opacity-100"),
+            ("grid-cols-2 grid-cols-3", "grid-cols-3"),
+            ("col-span-2 col-span-full", "col-span-full"),
+            ("cursor-pointer cursor-default", "cursor-default"),
+            (
+                "duration-150 duration-300 ease-in ease-out",
+                "duration-300 ease-out",
+            ),
+            ("visible invisible", "invisible"),
+            ("sr-only not-sr-only", "not-sr-only"),
+            // Variants.
+            ("hover:bg-red-500 hover:bg-blue-500", "hover:bg-blue-500"),
+            (
+                "hover:bg-red-500 bg-blue-500",
+                "hover:bg-red-500 bg-blue-500",
+            ),
+            ("md:px-4 lg:px-2", "md:px-4 lg:px-2"),
+            ("hover:focus:p-2 focus:hover:p-4", "focus:hover:p-4"),
+            ("md:hover:p-2 md:p-4", "md:hover:p-2 md:p-4"),
+            ("[&>*]:p-2 [&>*]:p-4", "[&>*]:p-4"),
+            (
+                "[&>*]:hover:p-2 hover:[&>*]:p-4",
+                "[&>*]:hover:p-2 hover:[&>*]:p-4",
+            ),
+            (
+                "data-[state=open]:p-2 data-[state=open]:p-4",
+                "data-[state=open]:p-4",
+            ),
+            // Important modifier.
+            ("!p-2 p-4", "!p-2 p-4"),
+            ("!p-2 !p-4", "!p-4"),
+            ("p-2! !p-4", "!p-4"),
+            // Arbitrary properties.
+            (
+                "[mask-type:luminance] [mask-type:alpha]",
+                "[mask-type:alpha]",
+            ),
+            (
+                "[mask-type:luminance] [--x:1]",
+                "[mask-type:luminance] [--x:1]",
+            ),
+        ] {
+            assert_eq!(expected, merge(&config, input), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_merge_config() {
+        let config = TailwindConfig::default()
+            .with_colors(["primary", "brand"])
+            .with_font_sizes(["huge"])
+            .with_font_families(["display"])
+            .with_shadows(["card"]);
+
+        for (input, expected) in [
+            ("bg-red-500 bg-primary", "bg-primary"),
+            ("bg-primary bg-brand-500", "bg-brand-500"),
+            ("text-primary text-huge", "text-primary text-huge"),
+            ("text-lg text-huge", "text-huge"),
+            ("font-sans font-display", "font-display"),
+            ("shadow-lg shadow-card", "shadow-card"),
+            ("border-2 border-primary", "border-2 border-primary"),
+        ] {
+            assert_eq!(expected, merge(&config, input), "{input}");
+        }
+
+        // Without configuration, custom theme values are not recognized.
+        assert_eq!(
+            "bg-red-500 bg-primary",
+            merge(&TailwindConfig::default(), "bg-red-500 bg-primary")
+        );
+    }
+}
@@ -0,0 +1,432 @@
+use std::{
+    fmt::{self, Display},
+    sync::Arc,
+};
+
+use indexmap::IndexMap;
+use leptos::{
+    logging::warn,
+    prelude::{provide_context, use_context},
+};
+
+use crate::{
+    sheet::{class_name, register},
+    style::{InnerStyle, Style},
+    value::StyleValue,
+};
+
+/// Groups of design tokens.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TokenGroup {
+    Color,
+    Spacing,
+    Radius,
+    FontSize,
+    Shadow,
+}
+
+impl TokenGroup {
+    /// Returns the name used in token references and custom properties.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Color => "color",
+            Self::Spacing => "spacing",
+            Self::Radius => "radius",
+            Self::FontSize => "font-size",
+            Self::Shadow => "shadow",
+        }
+    }
+
+    fn parse(group: &str) -> Option<Self> {
+        [
+            Self::Color,
+            Self::Spacing,
+            Self::Radius,
+            Self::FontSize,
+            Self::Shadow,
+        ]
+        .into_iter()
+        .find(|candidate| candidate.as_str() == group)
+    }
+}
+
+/// A design token, referenced in style values as `theme(group.name)`, e.g. `theme(color.primary)`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Token {
+    pub group: TokenGroup,
+    pub name: String,
+}
+
+impl Token {
+    pub fn new(group: TokenGroup, name: &str) -> Self {
+        Self {
+            group,
+            name: name.to_string(),
+        }
+    }
+
+    pub fn color(name: &str) -> Self {
+        Self::new(TokenGroup::Color, name)
+    }
+
+    pub fn spacing(name: &str) -> Self {
+        Self::new(TokenGroup::Spacing, name)
+    }
+
+    pub fn radius(name: &str) -> Self {
+        Self::new(TokenGroup::Radius, name)
+    }
+
+    pub fn font_size(name: &str) -> Self {
+        Self::new(TokenGroup::FontSize, name)
+    }
+
+    pub fn shadow(name: &str) -> Self {
+        Self::new(TokenGroup::Shadow, name)
+    }
+
+    /// Parses the contents of a token reference, e.g. `color.primary`.
+    pub fn parse(reference: &str) -> Option<Self> {
+        let (group, name) = reference.trim().split_once('.')?;
+
+        Some(Self::new(TokenGroup::parse(group)?, name)).filter(|token| !token.name.is_empty())
+    }
+
+    /// Returns the custom property defining this token, e.g. `--color-primary`.
+    ///
+    /// ASCII characters that are not valid in custom property names are hex escaped, e.g. `spacing.1.5` is defined
+    /// by `--spacing-1\00002e5`, so different names never map to the same property.
+    pub fn custom_property(&self) -> String {
+        let mut property = format!("--{}-", self.group.as_str());
+        for char in self.name.chars() {
+            if char.is_ascii_alphanumeric() || matches!(char, '-' | '_') || !char.is_ascii() {
+                property.push(char);
+            } else {
+                property.push_str(&format!("\\{:06x}", char as u32));
+            }
+        }
+
+        property
+    }
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "theme({}.{})", self.group.as_str(), self.name)
+    }
+}
+
+impl From<Token> for StyleValue {
+    fn from(value: Token) -> Self {
+        StyleValue::new(value.to_string())
+    }
+}
+
+/// How token references are resolved.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum TokenResolution {
+    /// Replace references with `var(--token)`, the tokens are defined by the root rule of the theme.
+    #[default]
+    CustomProperty,
+    /// Replace references with the token values.
+    Literal,
+}
+
+/// Design tokens, resolved in style values when rendering.
+///
+/// Provide a theme with [`provide_theme`]:
+///
+/// ```
+/// use leptos_style::{provide_theme, Style, Theme};
+///
+/// # let owner = leptos::prelude::Owner::new();
+/// # owner.set();
+/// provide_theme(
+///     Theme::new()
+///         .with_color("primary", "#2563eb")
+///         .with_spacing("4", "1rem"),
+/// );
+///
+/// let style = Style::from("color: red;").with_defaults([("padding", "theme(spacing.4)")]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Theme {
+    pub tokens: IndexMap<Token, String>,
+    pub resolution: TokenResolution,
+}
+
+impl Theme {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_token(mut self, token: Token, value: &str) -> Self {
+        self.tokens.insert(token, value.to_string());
+        self
+    }
+
+    pub fn with_color(self, name: &str, value: &str) -> Self {
+        self.with_token(Token::color(name), value)
+    }
+
+    pub fn with_spacing(self, name: &str, value: &str) -> Self {
+        self.with_token(Token::spacing(name), value)
+    }
+
+    pub fn with_radius(self, name: &str, value: &str) -> Self {
+        self.with_token(Token::radius(name), value)
+    }
+
+    pub fn with_font_size(self, name: &str, value: &str) -> Self {
+        self.with_token(Token::font_size(name), value)
+    }
+
+    pub fn with_shadow(self, name: &str, value: &str) -> Self {
+        self.with_token(Token::shadow(name), value)
+    }
+
+    pub fn with_resolution(mut self, resolution: TokenResolution) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Replaces token references in a value. Unknown tokens are kept and logged.
+    pub fn resolve(&self, value: &str) -> String {
+        let mut resolved = String::with_capacity(value.len());
+        let mut rest = value;
+
+        while let Some(start) = rest.find("theme(") {
+            let Some(end) = rest[start..].find(')').map(|end| start + end) else {
+                break;
+            };
+
+            resolved.push_str(&rest[..start]);
+            let reference = &rest[start + "theme(".len()..end];
+
+            match Token::parse(reference).filter(|token| self.tokens.contains_key(token)) {
+                Some(token) => match self.resolution {
+                    TokenResolution::CustomProperty => {
+                        resolved.push_str(&format!("var({})", token.custom_property()))
+                    }
+                    TokenResolution::Literal => resolved.push_str(&self.tokens[&token]),
+                },
+                None => {
+                    warn!("unknown theme token `{reference}`");
+                    resolved.push_str(&rest[start..=end]);
+                }
+            }
+
+            rest = &rest[end + 1..];
+        }
+
+        resolved.push_str(rest);
+        resolved
+    }
+
+    /// Returns the custom property declarations defining the tokens.
+    pub fn to_style(&self) -> Style {
+        self.tokens
+            .iter()
+            .map(|(token, value)| (token.custom_property(), value.clone()))
+            .collect()
+    }
+
+    /// Returns a `:root` rule with the custom property declarations defining the tokens.
+    pub fn to_css(&self) -> String {
+        format!(":root {{ {} }}", self.to_style().prepare_for_rule())
+    }
+}
+
+/// Provides a [`Theme`] for resolving token references when styles are rendered.
+///
+/// With [`TokenResolution::CustomProperty`], the root rule of the theme is registered like scoped styles: it is
+/// added to the [`StyleSheet`](crate::StyleSheet) from context if there is one (e.g. during SSR). Otherwise, it is
+/// injected into the document head on the client.
+pub fn provide_theme(theme: Theme) {
+    if theme.resolution == TokenResolution::CustomProperty {
+        let css = theme.to_css();
+        register(&class_name(&css), css);
+    }
+
+    provide_context(ThemeContext(Arc::new(theme)));
+}
+
+/// The [`Theme`] in context, shared so it is not cloned when resolving tokens.
+#[derive(Clone)]
+struct ThemeContext(Arc<Theme>);
+
+/// Returns the [`Theme`] from context, if any.
+pub fn use_theme() -> Option<Theme> {
+    use_context::<ThemeContext>().map(|theme| Theme::clone(&theme.0))
+}
+
+impl Style {
+    /// Replaces token references in values, see [`Theme::resolve`].
+    pub fn resolve_tokens(self, theme: &Theme) -> Self {
+        if self.is_reactive() {
+            let theme = theme.clone();
+            return self.map_static(move |style| style.resolve_tokens(&theme));
+        }
+        if !self.has_token_references() {
+            return self;
+        }
+        // String styles stay strings, so using a theme does not change how they are rendered.
+        if let Some(InnerStyle::String(string)) = &self.0 {
+            return Style(Some(InnerStyle::String(theme.resolve(string))));
+        }
+
+        let mut style = self;
+        let references = style
+            .declarations()
+            .filter(|(_, value)| value.contains("theme("))
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect::<Vec<_>>();
+
+        for (key, value) in references {
+            style.set(
+                key,
+                StyleValue {
+                    value: theme.resolve(&value).into(),
+                    important: value.important,
+                },
+            );
+        }
+        style
+    }
+
+    /// Resolves token references with the [`Theme`] from context, if there is one.
+    pub(crate) fn resolve_tokens_with_context(self) -> Self {
+        if self.is_reactive() {
+            return self.map_static(Style::resolve_tokens_with_context);
+        }
+        if !self.has_token_references() {
+            return self;
+        }
+
+        match use_context::<ThemeContext>() {
+            Some(theme) => self.resolve_tokens(&theme.0),
+            None => self,
+        }
+    }
+
+    fn has_token_references(&self) -> bool {
+        match &self.0 {
+            None => false,
+            Some(InnerStyle::String(style)) => style.contains("theme("),
+            Some(InnerStyle::Structured(map)) => {
+                map.values().flatten().any(|value| value.contains("theme("))
+            }
+            Some(InnerStyle::Reactive(_) | InnerStyle::Conditional(_)) => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos::prelude::Owner;
+
+    use super::*;
+    use crate::sheet::provide_style_sheet;
+
+    fn theme() -> Theme {
+        Theme::new()
+            .with_color("primary", "#2563eb")
+            .with_spacing("1.5", "0.375rem")
+            .with_spacing("4", "1rem")
+            .with_radius("md", "0.375rem")
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Some(Token::color("primary")), Token::parse("color.primary"));
+        assert_eq!(Some(Token::spacing("1.5")), Token::parse(" spacing.1.5 "));
+        assert_eq!(Some(Token::font_size("lg")), Token::parse("font-size.lg"));
+        assert_eq!(None, Token::parse("colour.primary"));
+        assert_eq!(None, Token::parse("color."));
+        assert_eq!(None, Token::parse("color"));
+
+        assert_eq!(
+            "--spacing-1\\00002e5",
+            Token::spacing("1.5").custom_property()
+        );
+        assert_eq!("--spacing-1_5", Token::spacing("1_5").custom_property());
+        assert_eq!("theme(radius.md)", Token::radius("md").to_string());
+    }
+
+    #[test]
+    fn test_resolve() {
+        let theme = theme();
+        let literal = theme.clone().with_resolution(TokenResolution::Literal);
+
+        for (value, expected, expected_literal) in [
+            ("red", "red", "red"),
+            (
+                "theme(spacing.1.5) theme(spacing.4)",
+                "var(--spacing-1\\00002e5) var(--spacing-4)",
+                "0.375rem 1rem",
+            ),
+            (
+                "1px solid theme(color.primary)",
+                "1px solid var(--color-primary)",
+                "1px solid #2563eb",
+            ),
+            (
+                "theme(color.unknown)",
+                "theme(color.unknown)",
+                "theme(color.unknown)",
+            ),
+            (
+                "theme(color.primary",
+                "theme(color.primary",
+                "theme(color.primary",
+            ),
+        ] {
+            assert_eq!(expected, theme.resolve(value), "{value}");
+            assert_eq!(expected_literal, literal.resolve(value), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_resolve_tokens() {
+        assert_eq!(
+            Style::from([
+                ("border-radius", StyleValue::new("var(--radius-md)")),
+                ("color", StyleValue::important("var(--color-primary)")),
+                ("margin", StyleValue::new("0")),
+            ]),
+            Style::from("color: theme(color.primary) !important; margin: 0;")
+                .with_defaults([("border-radius", StyleValue::from(Token::radius("md")))])
+                .resolve_tokens(&theme())
+        );
+
+        assert_eq!(
+            Some(InnerStyle::String(
+                "color:var(--color-primary);  margin : 0".to_string()
+            )),
+            Style::from("color:theme(color.primary);  margin : 0")
+                .resolve_tokens(&theme())
+                .0
+        );
+    }
+
+    #[test]
+    fn test_provide_theme() {
+        let owner = Owner::new();
+        owner.set();
+
+        let sheet = provide_style_sheet();
+        provide_theme(theme());
+        assert_eq!(
+            ":root { --color-primary: #2563eb; --spacing-1\\00002e5: 0.375rem; --spacing-4: 1rem; \
+             --radius-md: 0.375rem; }",
+            sheet.to_css()
+        );
+
+        let mut html = String::new();
+        leptos::tachys::html::style::IntoStyle::to_html(
+            Style::from([("padding", "theme(spacing.4)")]),
+            &mut html,
+        );
+        assert_eq!("padding: var(--spacing-4);", html);
+    }
+}
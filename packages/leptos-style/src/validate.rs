@@ -0,0 +1,720 @@
+use std::fmt::{self, Display};
+
+use leptos_style_properties::{edit_distance, PROPERTIES};
+
+use crate::{
+    custom_property::is_custom_property, parse::split_components, property::normalize_property,
+    style::Style,
+};
+
+const CSS_WIDE_KEYWORDS: [&str; 5] = ["inherit", "initial", "unset", "revert", "revert-layer"];
+
+/// Functions whose result cannot be validated without computing the style.
+const SUBSTITUTION_FUNCTIONS: [&str; 4] = ["var(", "env(", "attr(", "theme("];
+
+const LENGTH_UNITS: [&str; 28] = [
+    "px", "em", "rem", "ex", "ch", "cap", "ic", "lh", "rlh", "vw", "vh", "vi", "vb", "vmin",
+    "vmax", "svw", "svh", "lvw", "lvh", "dvw", "dvh", "cqw", "cqh", "cqmin", "cqmax", "cm", "mm",
+    "in",
+];
+
+const LENGTH_KEYWORDS: [&str; 19] = [
+    "auto",
+    "none",
+    "normal",
+    "fit-content",
+    "min-content",
+    "max-content",
+    "stretch",
+    "thin",
+    "medium",
+    "thick",
+    "xx-small",
+    "x-small",
+    "small",
+    "large",
+    "x-large",
+    "xx-large",
+    "xxx-large",
+    "smaller",
+    "larger",
+];
+
+const COLOR_FUNCTIONS: [&str; 13] = [
+    "rgb(",
+    "rgba(",
+    "hsl(",
+    "hsla(",
+    "hwb(",
+    "lab(",
+    "lch(",
+    "oklab(",
+    "oklch(",
+    "color(",
+    "color-mix(",
+    "light-dark(",
+    "contrast-color(",
+];
+
+/// Named colors, including `currentcolor` and `transparent`.
+const NAMED_COLORS: [&str; 150] = [
+    "aliceblue",
+    "antiquewhite",
+    "aqua",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanchedalmond",
+    "blue",
+    "blueviolet",
+    "brown",
+    "burlywood",
+    "cadetblue",
+    "chartreuse",
+    "chocolate",
+    "coral",
+    "cornflowerblue",
+    "cornsilk",
+    "crimson",
+    "currentcolor",
+    "cyan",
+    "darkblue",
+    "darkcyan",
+    "darkgoldenrod",
+    "darkgray",
+    "darkgreen",
+    "darkgrey",
+    "darkkhaki",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkorange",
+    "darkorchid",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darkslateblue",
+    "darkslategray",
+    "darkslategrey",
+    "darkturquoise",
+    "darkviolet",
+    "deeppink",
+    "deepskyblue",
+    "dimgray",
+    "dimgrey",
+    "dodgerblue",
+    "firebrick",
+    "floralwhite",
+    "forestgreen",
+    "fuchsia",
+    "gainsboro",
+    "ghostwhite",
+    "gold",
+    "goldenrod",
+    "gray",
+    "green",
+    "greenyellow",
+    "grey",
+    "honeydew",
+    "hotpink",
+    "indianred",
+    "indigo",
+    "ivory",
+    "khaki",
+    "lavender",
+    "lavenderblush",
+    "lawngreen",
+    "lemonchiffon",
+    "lightblue",
+    "lightcoral",
+    "lightcyan",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightgrey",
+    "lightpink",
+    "lightsalmon",
+    "lightseagreen",
+    "lightskyblue",
+    "lightslategray",
+    "lightslategrey",
+    "lightsteelblue",
+    "lightyellow",
+    "lime",
+    "limegreen",
+    "linen",
+    "magenta",
+    "maroon",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumorchid",
+    "mediumpurple",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "midnightblue",
+    "mintcream",
+    "mistyrose",
+    "moccasin",
+    "navajowhite",
+    "navy",
+    "oldlace",
+    "olive",
+    "olivedrab",
+    "orange",
+    "orangered",
+    "orchid",
+    "palegoldenrod",
+    "palegreen",
+    "paleturquoise",
+    "palevioletred",
+    "papayawhip",
+    "peachpuff",
+    "peru",
+    "pink",
+    "plum",
+    "powderblue",
+    "purple",
+    "rebeccapurple",
+    "red",
+    "rosybrown",
+    "royalblue",
+    "saddlebrown",
+    "salmon",
+    "sandybrown",
+    "seagreen",
+    "seashell",
+    "sienna",
+    "silver",
+    "skyblue",
+    "slateblue",
+    "slategray",
+    "slategrey",
+    "snow",
+    "springgreen",
+    "steelblue",
+    "tan",
+    "teal",
+    "thistle",
+    "tomato",
+    "transparent",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "whitesmoke",
+    "yellow",
+    "yellowgreen",
+];
+
+/// Value grammar of a property.
+enum Grammar {
+    /// Lengths, percentages and length keywords, e.g. `width` or `margin`.
+    Length,
+    /// A single color.
+    Color,
+    /// A number, optionally with keywords (e.g. `opacity` or `font-weight`).
+    Number(&'static [&'static str]),
+    /// An integer, optionally with keywords (e.g. `z-index`).
+    Integer(&'static [&'static str]),
+    /// Space-separated keywords (e.g. `display`).
+    Keywords(&'static [&'static str]),
+}
+
+fn grammar(property: &str) -> Option<Grammar> {
+    Some(match property {
+        "width" | "height" | "min-width" | "min-height" | "max-width" | "max-height"
+        | "inline-size" | "block-size" | "top" | "right" | "bottom" | "left" | "inset"
+        | "margin" | "margin-top" | "margin-right" | "margin-bottom" | "margin-left"
+        | "padding" | "padding-top" | "padding-right" | "padding-bottom" | "padding-left"
+        | "gap" | "row-gap" | "column-gap" | "font-size" | "letter-spacing" | "word-spacing"
+        | "text-indent" | "flex-basis" | "border-radius" | "border-width" | "outline-width"
+        | "outline-offset" => Grammar::Length,
+        "color"
+        | "background-color"
+        | "border-color"
+        | "border-top-color"
+        | "border-right-color"
+        | "border-bottom-color"
+        | "border-left-color"
+        | "outline-color"
+        | "text-decoration-color"
+        | "caret-color"
+        | "accent-color" => Grammar::Color,
+        "opacity" | "flex-grow" | "flex-shrink" | "fill-opacity" | "stroke-opacity" => {
+            Grammar::Number(&[])
+        }
+        "font-weight" => Grammar::Number(&["normal", "bold", "bolder", "lighter"]),
+        "z-index" => Grammar::Integer(&["auto"]),
+        "order" => Grammar::Integer(&[]),
+        "display" => Grammar::Keywords(&[
+            "block",
+            "inline",
+            "inline-block",
+            "flex",
+            "inline-flex",
+            "grid",
+            "inline-grid",
+            "flow",
+            "flow-root",
+            "none",
+            "contents",
+            "table",
+            "table-row",
+            "table-cell",
+            "table-column",
+            "table-row-group",
+            "table-header-group",
+            "table-footer-group",
+            "table-column-group",
+            "table-caption",
+            "inline-table",
+            "list-item",
+            "run-in",
+            "ruby",
+            "math",
+        ]),
+        "position" => Grammar::Keywords(&["static", "relative", "absolute", "fixed", "sticky"]),
+        "visibility" => Grammar::Keywords(&["visible", "hidden", "collapse"]),
+        "box-sizing" => Grammar::Keywords(&["content-box", "border-box"]),
+        "overflow" | "overflow-x" | "overflow-y" => {
+            Grammar::Keywords(&["visible", "hidden", "clip", "scroll", "auto"])
+        }
+        "float" => Grammar::Keywords(&["left", "right", "none", "inline-start", "inline-end"]),
+        "text-align" => Grammar::Keywords(&[
+            "start",
+            "end",
+            "left",
+            "right",
+            "center",
+            "justify",
+            "match-parent",
+        ]),
+        "flex-direction" => Grammar::Keywords(&["row", "row-reverse", "column", "column-reverse"]),
+        "flex-wrap" => Grammar::Keywords(&["nowrap", "wrap", "wrap-reverse"]),
+        "font-style" => Grammar::Keywords(&["normal", "italic", "oblique"]),
+        "text-transform" => Grammar::Keywords(&[
+            "none",
+            "capitalize",
+            "uppercase",
+            "lowercase",
+            "full-width",
+            "full-size-kana",
+        ]),
+        "white-space" => Grammar::Keywords(&[
+            "normal",
+            "pre",
+            "nowrap",
+            "pre-wrap",
+            "pre-line",
+            "break-spaces",
+        ]),
+        "pointer-events" => Grammar::Keywords(&["auto", "none"]),
+        "user-select" => Grammar::Keywords(&["auto", "text", "none", "contain", "all"]),
+        "object-fit" => Grammar::Keywords(&["fill", "contain", "cover", "none", "scale-down"]),
+        _ => return None,
+    })
+}
+
+/// Why a declaration is invalid.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ValidationReason {
+    /// The property is not a known CSS property.
+    UnknownProperty,
+    /// A non-zero length is missing its unit.
+    MissingUnit,
+    /// The value does not match the grammar of the property.
+    InvalidValue,
+}
+
+impl Display for ValidationReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownProperty => write!(f, "unknown property"),
+            Self::MissingUnit => write!(f, "missing unit"),
+            Self::InvalidValue => write!(f, "invalid value"),
+        }
+    }
+}
+
+/// An invalid declaration found by [`Style::validate`].
+///
+/// The suggestion is a property name for [`ValidationReason::UnknownProperty`], and a value otherwise.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ValidationDiagnostic {
+    pub property: String,
+    pub value: String,
+    pub reason: ValidationReason,
+    pub suggestion: Option<String>,
+}
+
+impl Display for ValidationDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid style declaration `{}: {}`: {}",
+            self.property, self.value, self.reason
+        )?;
+
+        match (&self.suggestion, self.reason) {
+            (Some(suggestion), ValidationReason::UnknownProperty) => {
+                write!(f, ", did you mean `{suggestion}: {}`?", self.value)
+            }
+            (Some(suggestion), _) => write!(f, ", did you mean `{}: {suggestion}`?", self.property),
+            (None, _) => Ok(()),
+        }
+    }
+}
+
+/// Returns the closest candidate, if it is close enough to be a typo.
+fn closest<'a>(input: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = (input.len() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(input, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Splits a number (e.g. `-1.5` in `-1.5rem`) from its unit.
+fn split_number(value: &str) -> Option<(f64, &str)> {
+    let end = value
+        .char_indices()
+        .find(|(index, char)| {
+            !(char.is_ascii_digit() || *char == '.' || (*index == 0 && matches!(char, '-' | '+')))
+        })
+        .map_or(value.len(), |(index, _)| index);
+
+    value[..end]
+        .parse()
+        .ok()
+        .map(|number| (number, &value[end..]))
+}
+
+fn is_function(value: &str) -> bool {
+    value.ends_with(')') && value.contains('(')
+}
+
+fn is_color(value: &str) -> bool {
+    if let Some(hex) = value.strip_prefix('#') {
+        return matches!(hex.len(), 3 | 4 | 6 | 8)
+            && hex.chars().all(|char| char.is_ascii_hexdigit());
+    }
+
+    let value = value.to_ascii_lowercase();
+    NAMED_COLORS.contains(&value.as_str())
+        || COLOR_FUNCTIONS
+            .iter()
+            .any(|function| value.starts_with(function) && value.ends_with(')'))
+}
+
+/// Validates the components of a value, returning the reason and the suggested value of the first invalid one.
+fn check_components(
+    value: &str,
+    check: impl Fn(&str) -> Option<(ValidationReason, Option<String>)>,
+) -> Option<(ValidationReason, Option<String>)> {
+    let components = split_components(value);
+
+    let (index, reason) = components
+        .iter()
+        .enumerate()
+        .find_map(|(index, component)| check(component).map(|reason| (index, reason)))?;
+
+    let (reason, replacement) = reason;
+    let suggestion = replacement.map(|replacement| {
+        components
+            .iter()
+            .enumerate()
+            .map(|(i, component)| {
+                if i == index {
+                    replacement.as_str()
+                } else {
+                    component
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    });
+
+    // Fix other components missing a unit in the same suggestion, e.g. `margin: 1 2`.
+    let suggestion = match (reason, suggestion) {
+        (ValidationReason::MissingUnit, Some(suggestion)) => Some(
+            split_components(&suggestion)
+                .into_iter()
+                .map(|component| match check(component) {
+                    Some((ValidationReason::MissingUnit, Some(replacement))) => replacement,
+                    _ => component.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+        (_, suggestion) => suggestion,
+    };
+
+    Some((reason, suggestion))
+}
+
+fn check_length(component: &str) -> Option<(ValidationReason, Option<String>)> {
+    if component == "/" || is_function(component) {
+        return None;
+    }
+
+    match split_number(component) {
+        Some((number, "")) if number != 0.0 => Some((
+            ValidationReason::MissingUnit,
+            Some(format!("{component}px")),
+        )),
+        Some((_, "")) | Some((_, "%")) => None,
+        Some((_, unit)) => {
+            let unit = unit.to_ascii_lowercase();
+            if LENGTH_UNITS.contains(&unit.as_str()) || ["pt", "pc", "q"].contains(&unit.as_str()) {
+                None
+            } else {
+                let number = &component[..component.len() - unit.len()];
+                Some((
+                    ValidationReason::InvalidValue,
+                    closest(&unit, LENGTH_UNITS).map(|unit| format!("{number}{unit}")),
+                ))
+            }
+        }
+        None if LENGTH_KEYWORDS.contains(&component.to_ascii_lowercase().as_str()) => None,
+        None => Some((
+            ValidationReason::InvalidValue,
+            closest(component, LENGTH_KEYWORDS).map(str::to_string),
+        )),
+    }
+}
+
+fn check_keyword(
+    component: &str,
+    keywords: &'static [&'static str],
+) -> Option<(ValidationReason, Option<String>)> {
+    (!keywords.contains(&component.to_ascii_lowercase().as_str())).then(|| {
+        (
+            ValidationReason::InvalidValue,
+            closest(component, keywords.iter().copied()).map(str::to_string),
+        )
+    })
+}
+
+fn check_value(property: &str, value: &str) -> Option<(ValidationReason, Option<String>)> {
+    let trimmed = value.trim();
+    if trimmed.is_empty()
+        || CSS_WIDE_KEYWORDS.contains(&trimmed.to_ascii_lowercase().as_str())
+        || SUBSTITUTION_FUNCTIONS
+            .iter()
+            .any(|function| trimmed.contains(function))
+    {
+        return None;
+    }
+
+    match grammar(property)? {
+        Grammar::Length => check_components(trimmed, check_length),
+        Grammar::Color => (!is_color(trimmed)).then(|| {
+            (
+                ValidationReason::InvalidValue,
+                closest(&trimmed.to_ascii_lowercase(), NAMED_COLORS).map(str::to_string),
+            )
+        }),
+        Grammar::Number(keywords) => (split_number(trimmed)
+            .is_none_or(|(_, unit)| !unit.is_empty() && unit != "%")
+            && !is_function(trimmed))
+        .then(|| check_keyword(trimmed, keywords))
+        .flatten(),
+        Grammar::Integer(keywords) => (trimmed.parse::<i64>().is_err() && !is_function(trimmed))
+            .then(|| check_keyword(trimmed, keywords))
+            .flatten(),
+        Grammar::Keywords(keywords) => {
+            check_components(trimmed, |component| check_keyword(component, keywords))
+        }
+    }
+}
+
+/// Validates a declaration, returning a diagnostic if it is invalid.
+///
+/// The property name is checked against a list of standard properties, and the value against the grammar of common
+/// properties (e.g. lengths, colors and keywords). Values using `var()` or other substitution functions are not
+/// checked, nor are custom and vendor-prefixed properties. camelCase names are converted to kebab-case first.
+pub fn validate_declaration(property: &str, value: &str) -> Option<ValidationDiagnostic> {
+    let property = normalize_property(property);
+    if is_custom_property(&property) || property.starts_with('-') {
+        return None;
+    }
+
+    let lowercase = property.to_ascii_lowercase();
+    let (reason, suggestion) = if PROPERTIES.binary_search(&lowercase.as_str()).is_err() {
+        (
+            ValidationReason::UnknownProperty,
+            closest(&lowercase, PROPERTIES.iter().copied()).map(str::to_string),
+        )
+    } else {
+        check_value(&lowercase, value)?
+    };
+
+    Some(ValidationDiagnostic {
+        property: property.into_owned(),
+        value: value.to_string(),
+        reason,
+        suggestion,
+    })
+}
+
+impl Style {
+    /// Validates the declarations, see [`validate_declaration`].
+    ///
+    /// Validation is optional: invalid declarations are still rendered. With the `debug` feature, diagnostics are
+    /// logged when a style is rendered.
+    pub fn validate(&self) -> Vec<ValidationDiagnostic> {
+        self.declarations()
+            .filter_map(|(property, value)| validate_declaration(&property, &value))
+            .collect()
+    }
+}
+
+/// Logs the diagnostics of a rendered style, each at most once until `MAX_LOGGED` diagnostics have been logged.
+#[cfg(feature = "debug")]
+pub(crate) fn log_diagnostics(style: &Style) {
+    use std::{
+        collections::HashSet,
+        sync::{Mutex, OnceLock},
+    };
+
+    /// Number of logged diagnostics after which they are forgotten, so rendering dynamic values (e.g. during SSR)
+    /// does not grow the set without limit.
+    const MAX_LOGGED: usize = 1024;
+
+    static LOGGED: OnceLock<Mutex<HashSet<ValidationDiagnostic>>> = OnceLock::new();
+
+    let diagnostics = style.validate();
+    if diagnostics.is_empty() {
+        return;
+    }
+
+    let mut logged = LOGGED
+        .get_or_init(Default::default)
+        .lock()
+        .expect("logged diagnostics lock should not be poisoned");
+    for diagnostic in diagnostics {
+        if !logged.contains(&diagnostic) {
+            leptos::logging::warn!("{diagnostic}");
+            if logged.len() >= MAX_LOGGED {
+                logged.clear();
+            }
+            logged.insert(diagnostic);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_colors() {
+        assert!(NAMED_COLORS.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_validate_declaration() {
+        for (property, value, expected) in [
+            ("color", "red", None),
+            ("backgroundColor", "#fff", None),
+            ("--primary", "anything", None),
+            ("border-block-color", "red", None),
+            ("marker-end", "url(#arrow)", None),
+            ("-webkit-line-clamp", "2", None),
+            ("width", "var(--width)", None),
+            ("width", "calc(100% - 1rem)", None),
+            ("margin", "0 auto", None),
+            ("border-radius", "1rem / 50%", None),
+            ("display", "inline flex", None),
+            ("z-index", "auto", None),
+            ("opacity", "0.5", None),
+            ("opacity", "50%", None),
+            ("font-weight", "bold", None),
+            ("color", "inherit", None),
+            (
+                "colr",
+                "red",
+                Some((ValidationReason::UnknownProperty, Some("color"))),
+            ),
+            (
+                "backgroud-color",
+                "red",
+                Some((ValidationReason::UnknownProperty, Some("background-color"))),
+            ),
+            (
+                "frobnicate",
+                "red",
+                Some((ValidationReason::UnknownProperty, None)),
+            ),
+            (
+                "width",
+                "10",
+                Some((ValidationReason::MissingUnit, Some("10px"))),
+            ),
+            (
+                "margin",
+                "1 auto 2",
+                Some((ValidationReason::MissingUnit, Some("1px auto 2px"))),
+            ),
+            (
+                "padding",
+                "1pz",
+                Some((ValidationReason::InvalidValue, Some("1px"))),
+            ),
+            (
+                "display",
+                "inline flx",
+                Some((ValidationReason::InvalidValue, Some("inline flex"))),
+            ),
+            (
+                "color",
+                "whte",
+                Some((ValidationReason::InvalidValue, Some("white"))),
+            ),
+            (
+                "color",
+                "#ggg",
+                Some((ValidationReason::InvalidValue, None)),
+            ),
+            (
+                "z-index",
+                "1.5",
+                Some((ValidationReason::InvalidValue, None)),
+            ),
+        ] {
+            assert_eq!(
+                expected.map(|(reason, suggestion)| ValidationDiagnostic {
+                    property: normalize_property(property).into_owned(),
+                    value: value.to_string(),
+                    reason,
+                    suggestion: suggestion.map(str::to_string),
+                }),
+                validate_declaration(property, value),
+                "{property}: {value}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let diagnostics = Style::from("colr: red; width: 10; height: 1rem;").validate();
+
+        assert_eq!(
+            vec![
+                "invalid style declaration `colr: red`: unknown property, did you mean `color: red`?",
+                "invalid style declaration `width: 10`: missing unit, did you mean `width: 10px`?",
+            ],
+            diagnostics
+                .iter()
+                .map(ValidationDiagnostic::to_string)
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+use leptos_style_properties::strip_important;
+
+/// A value in a structured style, with its priority.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StyleValue {
+    pub value: Cow<'static, str>,
+    pub important: bool,
+}
+
+impl StyleValue {
+    /// Creates a value with normal priority.
+    pub fn new<V: Into<Cow<'static, str>>>(value: V) -> Self {
+        Self {
+            value: value.into(),
+            important: false,
+        }
+    }
+
+    /// Creates a value with `!important` priority.
+    pub fn important<V: Into<Cow<'static, str>>>(value: V) -> Self {
+        Self {
+            value: value.into(),
+            important: true,
+        }
+    }
+
+    /// Returns the priority as expected by `CSSStyleDeclaration.setProperty()`.
+    pub fn priority(&self) -> &'static str {
+        if self.important {
+            "important"
+        } else {
+            ""
+        }
+    }
+}
+
+impl Deref for StyleValue {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl Display for StyleValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.important {
+            write!(f, "{} !important", self.value)
+        } else {
+            write!(f, "{}", self.value)
+        }
+    }
+}
+
+/// Serializes as a string, with a trailing `!important` for important values.
+#[cfg(feature = "serde")]
+impl serde::Serialize for StyleValue {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for StyleValue {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(StyleValue::from)
+    }
+}
+
+impl From<&str> for StyleValue {
+    fn from(value: &str) -> StyleValue {
+        match strip_important(value) {
+            Some(value) => StyleValue::important(value.to_string()),
+            None => StyleValue::new(value.to_string()),
+        }
+    }
+}
+
+/// Borrows static values instead of copying them.
+impl From<Cow<'static, str>> for StyleValue {
+    fn from(value: Cow<'static, str>) -> StyleValue {
+        match value {
+            Cow::Borrowed(value) => match strip_important(value) {
+                Some(value) => StyleValue::important(value),
+                None => StyleValue::new(value),
+            },
+            Cow::Owned(value) => StyleValue::from(value),
+        }
+    }
+}
+
+impl From<String> for StyleValue {
+    fn from(mut value: String) -> StyleValue {
+        match strip_important(&value) {
+            Some(stripped) => {
+                // The stripped value is a prefix of the value.
+                value.truncate(stripped.len());
+                StyleValue::important(value)
+            }
+            None => StyleValue::new(value),
+        }
+    }
+}
+
+impl From<&String> for StyleValue {
+    fn from(value: &String) -> StyleValue {
+        StyleValue::from(value.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(StyleValue::new("red"), StyleValue::from("red"));
+        assert_eq!(
+            StyleValue::important("red"),
+            StyleValue::from("red !important")
+        );
+        assert_eq!(
+            StyleValue::important("red"),
+            StyleValue::from("red ! IMPORTANT ")
+        );
+        assert_eq!(
+            StyleValue::important("red"),
+            StyleValue::from("red!important".to_string())
+        );
+        assert_eq!(StyleValue::new("url(a!b)"), StyleValue::from("url(a!b)"));
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!("red", StyleValue::new("red").to_string());
+        assert_eq!("red !important", StyleValue::important("red").to_string());
+    }
+}
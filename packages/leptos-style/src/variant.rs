@@ -0,0 +1,307 @@
+use leptos::logging::error;
+
+use crate::{
+    parse::Scanner,
+    sheet::{class_name, register},
+    style::Style,
+    value::StyleValue,
+};
+
+/// Condition under which a variant of a [`StyleVariants`] applies.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Condition {
+    /// Selector appended to the generated class, e.g. `:hover` or `[data-state="open"]`. Selector lists (e.g.
+    /// `:hover, :focus`) are rejected, use `:is(:hover, :focus)` instead.
+    Selector(String),
+    /// Media query, e.g. `(min-width: 640px)`.
+    Media(String),
+}
+
+impl Condition {
+    fn is_valid(&self) -> bool {
+        let (Self::Selector(condition) | Self::Media(condition)) = self;
+
+        // `</` is escaped by `StyleSheet::to_html`, `<` and `>` are valid in selectors and media ranges.
+        if condition.contains(['{', '}', ';']) {
+            return false;
+        }
+
+        // A selector list (e.g. `.a, body`) would apply the rule to elements without the generated class.
+        // Commas inside functional pseudo-classes (e.g. `:is(.a, .b)`) and quotes are scoped.
+        match self {
+            Self::Selector(selector) => {
+                !Scanner::new(selector).any(|scanned| scanned.is_top_level(','))
+            }
+            Self::Media(_) => true,
+        }
+    }
+}
+
+/// A [`Style`] with nested variants for pseudo-states and media queries.
+///
+/// Variants are compiled into style sheet rules for a generated class, see [`StyleVariants::class`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyleVariants {
+    pub base: Style,
+    pub variants: Vec<(Condition, StyleVariants)>,
+}
+
+impl StyleVariants {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a property of the base style.
+    pub fn with_property<V: Into<StyleValue>>(mut self, name: &str, value: V) -> Self {
+        self.base = self.base.with_property(name, value);
+        self
+    }
+
+    /// Adds a variant applying under `condition`.
+    pub fn variant<F: FnOnce(Self) -> Self>(mut self, condition: Condition, f: F) -> Self {
+        self.variants.push((condition, f(Self::new())));
+        self
+    }
+
+    /// Adds a variant for a selector appended to the generated class, e.g. `[aria-expanded="true"]`.
+    pub fn selector<F: FnOnce(Self) -> Self>(self, selector: &str, f: F) -> Self {
+        self.variant(Condition::Selector(selector.to_string()), f)
+    }
+
+    pub fn hover<F: FnOnce(Self) -> Self>(self, f: F) -> Self {
+        self.selector(":hover", f)
+    }
+
+    pub fn focus<F: FnOnce(Self) -> Self>(self, f: F) -> Self {
+        self.selector(":focus", f)
+    }
+
+    pub fn focus_visible<F: FnOnce(Self) -> Self>(self, f: F) -> Self {
+        self.selector(":focus-visible", f)
+    }
+
+    pub fn active<F: FnOnce(Self) -> Self>(self, f: F) -> Self {
+        self.selector(":active", f)
+    }
+
+    pub fn disabled<F: FnOnce(Self) -> Self>(self, f: F) -> Self {
+        self.selector(":disabled", f)
+    }
+
+    /// Adds a variant for a media query, e.g. `(min-width: 640px)`.
+    pub fn media<F: FnOnce(Self) -> Self>(self, query: &str, f: F) -> Self {
+        self.variant(Condition::Media(query.to_string()), f)
+    }
+
+    fn push_rules(
+        &self,
+        rules: &mut Vec<String>,
+        selector: &str,
+        media: &[&str],
+        base: bool,
+        important: bool,
+    ) {
+        if base {
+            let mut style = self.base.clone().prepare_for_rule();
+            if important {
+                style = style.important();
+            }
+
+            let declarations = style.to_string();
+            if !declarations.is_empty() {
+                let rule = format!("{selector} {{ {declarations} }}");
+                rules.push(match media {
+                    [] => rule,
+                    media => format!("@media {} {{ {rule} }}", media.join(" and ")),
+                });
+            }
+        }
+
+        for (condition, variant) in &self.variants {
+            if !condition.is_valid() {
+                error!("invalid style variant condition `{condition:?}`");
+                continue;
+            }
+
+            match condition {
+                Condition::Selector(suffix) => variant.push_rules(
+                    rules,
+                    &format!("{selector}{suffix}"),
+                    media,
+                    true,
+                    important,
+                ),
+                Condition::Media(query) => {
+                    let media = media
+                        .iter()
+                        .copied()
+                        .chain([query.as_str()])
+                        .collect::<Vec<_>>();
+                    variant.push_rules(rules, selector, &media, true, important);
+                }
+            }
+        }
+    }
+
+    fn rules(&self, class: &str, base: bool) -> String {
+        let mut rules = vec![];
+        self.push_rules(&mut rules, &format!(".{class}"), &[], base, !base);
+        rules.join("\n")
+    }
+
+    /// Returns the style sheet rules for all declarations, scoped to `class`.
+    pub fn to_css(&self, class: &str) -> String {
+        self.rules(class, true)
+    }
+
+    fn register(&self, base: bool) -> String {
+        let class = class_name(&self.rules("&", base));
+        register(&class, self.rules(&class, base));
+        class
+    }
+
+    /// Returns a generated class name for all declarations, registering its rules.
+    pub fn class(&self) -> String {
+        self.register(true)
+    }
+
+    /// Returns a generated class name for the variants only, registering their rules.
+    ///
+    /// Use this together with [`StyleVariants::inline`]. Variant declarations are marked `!important`, so they
+    /// override the inline base declarations.
+    pub fn variants_class(&self) -> String {
+        self.register(false)
+    }
+
+    /// Returns the base declarations, to render inline.
+    pub fn inline(&self) -> Style {
+        self.base.clone()
+    }
+}
+
+impl From<Style> for StyleVariants {
+    fn from(value: Style) -> Self {
+        Self {
+            base: value,
+            variants: vec![],
+        }
+    }
+}
+
+impl Style {
+    /// Adds a variant applying under `condition`, see [`StyleVariants`].
+    pub fn variant<F: FnOnce(StyleVariants) -> StyleVariants>(
+        self,
+        condition: Condition,
+        f: F,
+    ) -> StyleVariants {
+        StyleVariants::from(self).variant(condition, f)
+    }
+
+    pub fn selector<F: FnOnce(StyleVariants) -> StyleVariants>(
+        self,
+        selector: &str,
+        f: F,
+    ) -> StyleVariants {
+        StyleVariants::from(self).selector(selector, f)
+    }
+
+    pub fn hover<F: FnOnce(StyleVariants) -> StyleVariants>(self, f: F) -> StyleVariants {
+        StyleVariants::from(self).hover(f)
+    }
+
+    pub fn focus<F: FnOnce(StyleVariants) -> StyleVariants>(self, f: F) -> StyleVariants {
+        StyleVariants::from(self).focus(f)
+    }
+
+    pub fn focus_visible<F: FnOnce(StyleVariants) -> StyleVariants>(self, f: F) -> StyleVariants {
+        StyleVariants::from(self).focus_visible(f)
+    }
+
+    pub fn active<F: FnOnce(StyleVariants) -> StyleVariants>(self, f: F) -> StyleVariants {
+        StyleVariants::from(self).active(f)
+    }
+
+    pub fn disabled<F: FnOnce(StyleVariants) -> StyleVariants>(self, f: F) -> StyleVariants {
+        StyleVariants::from(self).disabled(f)
+    }
+
+    pub fn media<F: FnOnce(StyleVariants) -> StyleVariants>(
+        self,
+        query: &str,
+        f: F,
+    ) -> StyleVariants {
+        StyleVariants::from(self).media(query, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos::prelude::Owner;
+
+    use super::*;
+    use crate::sheet::provide_style_sheet;
+
+    #[test]
+    fn test_to_css() {
+        let style = Style::new()
+            .with_property("color", "black")
+            .hover(|s| s.with_property("color", "blue"))
+            .disabled(|s| s.with_property("opacity", "0.5"))
+            .media("(min-width: 640px)", |s| {
+                s.with_property("padding", "1rem")
+                    .focus_visible(|s| s.with_property("outline", "1px solid"))
+            })
+            .selector("{ color: red", |s| s.with_property("color", "red"))
+            .selector(", body", |s| s.with_property("display", "none"))
+            .selector(":is(:hover, [data-state=\"open, closed\"])", |s| {
+                s.with_property("color", "green")
+            });
+
+        assert_eq!(
+            [
+                ".a { color: black; }",
+                ".a:hover { color: blue; }",
+                ".a:disabled { opacity: 0.5; }",
+                "@media (min-width: 640px) { .a { padding: 1rem; } }",
+                "@media (min-width: 640px) { .a:focus-visible { outline: 1px solid; } }",
+                ".a:is(:hover, [data-state=\"open, closed\"]) { color: green; }",
+            ]
+            .join("\n"),
+            style.to_css("a")
+        );
+
+        let style = StyleVariants::new().media("(min-width: 640px)", |s| {
+            s.media("(prefers-reduced-motion: reduce)", |s| {
+                s.with_property("transition", "none")
+            })
+        });
+
+        assert_eq!(
+            "@media (min-width: 640px) and (prefers-reduced-motion: reduce) { .a { transition: none; } }",
+            style.to_css("a")
+        );
+    }
+
+    #[test]
+    fn test_class() {
+        let owner = Owner::new();
+        owner.set();
+
+        let sheet = provide_style_sheet();
+
+        let style = Style::from([("color", "black")]).hover(|s| s.with_property("color", "blue"));
+
+        let class = style.class();
+        assert_eq!(class, style.class());
+        assert_eq!(style.to_css(&class), sheet.to_css());
+
+        let variants_class = style.variants_class();
+        assert_ne!(class, variants_class);
+        assert_eq!(
+            format!(".{variants_class}:hover {{ color: blue !important; }}"),
+            sheet.to_css().lines().last().unwrap()
+        );
+        assert_eq!("color: black;", style.inline().to_string());
+    }
+}
@@ -0,0 +1,26 @@
+use leptos::prelude::*;
+use leptos_style::Class;
+
+#[component]
+fn Button(#[prop(into, optional)] class: Class, children: Children) -> impl IntoView {
+    view! {
+        <button class=class.with_defaults("button button-primary")>
+            {children()}
+        </button>
+    }
+}
+
+#[test]
+fn test_class() {
+    let owner = Owner::new();
+    owner.set();
+
+    let html = view! {
+        <Button class=[("button-primary", false), ("active", true), ("button", true)]>
+            "Click me"
+        </Button>
+    }
+    .to_html();
+
+    assert_eq!("<button class=\"button active\">Click me</button>", html);
+}
@@ -36,3 +36,22 @@ fn App() -> impl IntoView {
         </Button>
     }
 }
+
+#[test]
+fn test_reactive_style() {
+    let owner = Owner::new();
+    owner.set();
+
+    let color = RwSignal::new("white");
+    let html = view! {
+        <Button style=Style::reactive(move || [("color", color.get())])>
+            "Click me"
+        </Button>
+    }
+    .to_html();
+
+    assert_eq!(
+        "<button class=\"\" style=\"padding: 0.5rem; color: white;\">Click me</button>",
+        html
+    );
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "hoist")]
+
+use leptos::prelude::*;
+use leptos_style::{provide_style_sheet, Style};
+
+#[component]
+fn Item(children: Children) -> impl IntoView {
+    let style = Style::from("color: white; padding: 0.25rem 0.5rem;");
+
+    view! { <li class="item" style=style>{children()}</li> }
+}
+
+#[test]
+fn test_hoist() {
+    let owner = Owner::new();
+    owner.set();
+
+    let sheet = provide_style_sheet();
+
+    let html = view! {
+        <ul>
+            <Item>"One"</Item>
+            <Item>"Two"</Item>
+            <Item>"Three"</Item>
+        </ul>
+    }
+    .to_html();
+
+    let css = sheet.to_css();
+    let marker = css
+        .strip_prefix("[style*=\"")
+        .and_then(|css| css.split_once(':'))
+        .map(|(marker, _)| marker)
+        .expect("rule should start with an attribute selector");
+
+    assert_eq!(1, sheet.len());
+    assert_eq!(
+        format!(
+            "[style*=\"{marker}:\"][style*=\"{marker}:\"] {{ color: white; padding: 0.25rem 0.5rem; }}"
+        ),
+        css
+    );
+    assert_eq!(
+        format!(
+            "<ul>\
+             <li class=\"item\" style=\"color: white; padding: 0.25rem 0.5rem;\">One</li>\
+             <li class=\"item\" style=\"{marker}: 1;\">Two</li>\
+             <li class=\"item\" style=\"{marker}: 1;\">Three</li>\
+             </ul>"
+        ),
+        html
+    );
+}
+
+#[test]
+fn test_hoist_without_style_sheet() {
+    let owner = Owner::new();
+    owner.set();
+
+    let html = view! {
+        <ul>
+            <Item>"One"</Item>
+            <Item>"Two"</Item>
+        </ul>
+    }
+    .to_html();
+
+    assert_eq!(
+        2,
+        html.matches("style=\"color: white; padding: 0.25rem 0.5rem;\"")
+            .count()
+    );
+}
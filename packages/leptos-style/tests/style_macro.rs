@@ -0,0 +1,105 @@
+use leptos_style::{style, Style, StyleValue};
+
+#[test]
+fn test_style_macro() {
+    assert_eq!(Style::default(), style! {});
+
+    assert_eq!(
+        Style::from([("color", "red"), ("padding", "1rem 0.5rem")]),
+        style! {
+            color: red;
+            padding: 1rem 0.5rem;
+        },
+    );
+
+    assert_eq!(
+        Style::from([
+            ("background-color", StyleValue::new("rgb(0 0 0 / 50%)")),
+            ("margin", StyleValue::new("0 -1px")),
+            ("width", StyleValue::important("calc(100% - 2 * 1rem)")),
+            ("font-family", StyleValue::new("\"Open Sans\", sans-serif")),
+            ("--accent", StyleValue::new("var(--primary, blue)")),
+            ("user-select", StyleValue::new("none")),
+            ("-webkit-user-select", StyleValue::new("none")),
+            ("border", StyleValue::new("1px solid #e5e5e5")),
+        ]),
+        style! {
+            background-color: rgb(0 0 0 / 50%);
+            margin: 0 -1px;
+            width: calc(100% - 2 * 1rem) !important;
+            font-family: "Open Sans", sans-serif;
+            --accent: var(--primary, blue);
+            user-select: none;
+            -webkit-user-select: none;
+            border: 1px solid #e5e5e5
+        },
+    );
+}
+
+#[test]
+fn test_style_macro_interpolation() {
+    let width = 100;
+    let color = "red";
+
+    assert_eq!(
+        Style::from([
+            ("width", StyleValue::new("100px")),
+            ("color", StyleValue::new("red")),
+            ("height", StyleValue::new("calc(100px + {1rem})")),
+            ("background-color", StyleValue::new("#1e1e1e")),
+        ]),
+        style! {
+            width: {width}px;
+            color: {color};
+            height: calc({width}px + {"{1rem}"});
+            background-color: {"#1e1e1e"};
+        },
+    );
+}
+
+#[test]
+fn test_style_macro_string_literal() {
+    // `1em` and `1.5ex` are not valid Rust tokens.
+    assert_eq!(
+        Style::from([
+            ("font-size", StyleValue::new("1em")),
+            ("line-height", StyleValue::important("1.5ex")),
+            ("content", StyleValue::new("'→'")),
+            ("font-family", StyleValue::new("\"Open Sans\"")),
+        ]),
+        style! {
+            font-size: "1em";
+            line-height: "1.5ex" !important;
+            content: "'→'";
+            font-family: "\"Open Sans\"";
+        },
+    );
+}
+
+#[test]
+fn test_style_macro_important_string_literal() {
+    assert_eq!(
+        Style::from([
+            ("color", StyleValue::important("red")),
+            ("margin", StyleValue::important("1em")),
+        ]),
+        style! {
+            color: "red !important";
+            margin: "1em!important" !important;
+        },
+    );
+}
+
+#[test]
+fn test_style_macro_numeric_custom_property() {
+    assert_eq!(
+        Style::from([
+            ("--level-1", StyleValue::new("2")),
+            ("--space-2xl", StyleValue::new("3rem")),
+        ]),
+        style! {
+            --level-1: 2;
+            --space-2xl: "3rem";
+        },
+    );
+}
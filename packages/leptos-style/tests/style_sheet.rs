@@ -0,0 +1,45 @@
+use leptos::prelude::*;
+use leptos_style::{css, provide_style_sheet};
+
+#[component]
+fn Badge(children: Children) -> impl IntoView {
+    view! {
+        <span class=css! { color: white; padding: 0.25rem 0.5rem; }>
+            {children()}
+        </span>
+    }
+}
+
+#[test]
+fn test_style_sheet() {
+    let owner = Owner::new();
+    owner.set();
+
+    let sheet = provide_style_sheet();
+
+    let html = view! {
+        <div>
+            <Badge>"One"</Badge>
+            <Badge>"Two"</Badge>
+        </div>
+    }
+    .to_html();
+
+    let css = sheet.to_css();
+    let class = css
+        .strip_prefix('.')
+        .and_then(|css| css.split_once(' '))
+        .map(|(class, _)| class)
+        .expect("rule should start with a class selector");
+
+    assert_eq!(1, sheet.len());
+    assert_eq!(
+        format!(".{class} {{ color: white; padding: 0.25rem 0.5rem; }}"),
+        css
+    );
+    assert_eq!(2, html.matches(&format!("class=\"{class}\"")).count());
+    assert_eq!(
+        format!("<style data-leptos-style>{css}</style>"),
+        sheet.to_html()
+    );
+}